@@ -0,0 +1,58 @@
+#!/usr/bin/env python3
+"""Minimal engine speaking the TicTacToe engine protocol (see src/services/engine.rs).
+
+Wins when it can, blocks when it must, otherwise plays a random empty field.
+Run the game with: cargo run -- --engine "python3 engines/random_engine.py"
+"""
+import random
+import sys
+
+LINES = [(0, 1, 2), (3, 4, 5), (6, 7, 8), (0, 3, 6), (1, 4, 7), (2, 5, 8), (0, 4, 8), (2, 4, 6)]
+
+
+def send(line):
+    sys.stdout.write(line + "\n")
+    sys.stdout.flush()
+
+
+def winning_field(board, mark):
+    for line in LINES:
+        marks = [board[i] for i in line]
+        if marks.count(mark) == 2 and marks.count(".") == 1:
+            return line[marks.index(".")]
+    return None
+
+
+def best_move(board, side):
+    enemy = "o" if side == "x" else "x"
+    for mark in (side, enemy):
+        field = winning_field(board, mark)
+        if field is not None:
+            return field
+    return random.choice([i for i, mark in enumerate(board) if mark == "."])
+
+
+def main():
+    board, side = "." * 9, "o"
+    for line in sys.stdin:
+        parts = line.split()
+        if not parts:
+            continue
+        command = parts[0]
+        if command == "tictactoe":
+            send("id name RandomEngine")
+            send("tictactoeok")
+        elif command == "isready":
+            send("readyok")
+        elif command == "newgame":
+            board = "." * 9
+        elif command == "position" and len(parts) == 3:
+            board, side = parts[1], parts[2]
+        elif command == "go":
+            send("bestmove {}".format(best_move(board, side)))
+        elif command == "quit":
+            break
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,46 @@
+use crate::services::locale::tr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AiLevel{
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl AiLevel{
+    pub fn as_str(&self) -> &str{
+        match self {
+            AiLevel::Easy => "Easy",
+            AiLevel::Normal => "Normal",
+            AiLevel::Hard => "Hard",
+        }
+    }
+
+    pub fn label(&self) -> &str{
+        tr(self.as_str())
+    }
+
+    pub fn from_str(text: &str) -> Option<AiLevel>{
+        AiLevel::get_all().into_iter().find(|level| level.as_str() == text)
+    }
+
+    pub fn get_all() -> [AiLevel; 3]{
+        [AiLevel::Easy, AiLevel::Normal, AiLevel::Hard]
+    }
+
+    pub fn next(&self) -> AiLevel{
+        match self {
+            AiLevel::Easy => AiLevel::Normal,
+            AiLevel::Normal => AiLevel::Hard,
+            AiLevel::Hard => AiLevel::Easy,
+        }
+    }
+
+    pub fn previous(&self) -> AiLevel{
+        match self {
+            AiLevel::Easy => AiLevel::Hard,
+            AiLevel::Normal => AiLevel::Easy,
+            AiLevel::Hard => AiLevel::Normal,
+        }
+    }
+}
\ No newline at end of file
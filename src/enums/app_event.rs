@@ -0,0 +1,14 @@
+use std::time::Instant;
+use ratatui::crossterm::event::{KeyEvent, MouseEvent};
+
+/// Everything the main loop reacts to, in the order it happened.
+#[derive(Debug, Clone, Copy)]
+pub enum AppEvent{
+    Key(KeyEvent),
+    Mouse(MouseEvent),
+    Resize,
+    /// Fixed rate timer, views poll background work (AI, engines, network) and advance clocks on it.
+    Tick(Instant),
+    /// A background thread queued a message, views take it in `update` and the screen is drawn again.
+    Background,
+}
@@ -0,0 +1,101 @@
+/// Every piece of art in `game_art`, art packs override them by file name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArtEntry{
+    TicTacToe,
+    Settings,
+    YourTurn,
+    EnemyTurn,
+    CrossTurn,
+    CircleTurn,
+    YouWon,
+    YouLost,
+    CrossWon,
+    CircleWon,
+    Draw,
+    X,
+    O,
+    XLarge,
+    OLarge,
+    AngryFace,
+    SmileyFace,
+    ThinkingFace,
+    HappyFace,
+    Join,
+    Lobby,
+    Players,
+    Stats,
+}
+
+impl ArtEntry{
+    /// File name without the `.txt` extension.
+    pub fn name(&self) -> &'static str{
+        match self {
+            ArtEntry::TicTacToe => "tic_tac_toe",
+            ArtEntry::Settings => "settings",
+            ArtEntry::YourTurn => "your_turn",
+            ArtEntry::EnemyTurn => "enemy_turn",
+            ArtEntry::CrossTurn => "cross_turn",
+            ArtEntry::CircleTurn => "circle_turn",
+            ArtEntry::YouWon => "you_won",
+            ArtEntry::YouLost => "you_lost",
+            ArtEntry::CrossWon => "cross_won",
+            ArtEntry::CircleWon => "circle_won",
+            ArtEntry::Draw => "draw",
+            ArtEntry::X => "x",
+            ArtEntry::O => "o",
+            ArtEntry::XLarge => "x_large",
+            ArtEntry::OLarge => "o_large",
+            ArtEntry::AngryFace => "angry_face",
+            ArtEntry::SmileyFace => "smiley_face",
+            ArtEntry::ThinkingFace => "thinking_face",
+            ArtEntry::HappyFace => "happy_face",
+            ArtEntry::Join => "join",
+            ArtEntry::Lobby => "lobby",
+            ArtEntry::Players => "players",
+            ArtEntry::Stats => "stats",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<ArtEntry>{
+        ArtEntry::get_all().into_iter().find(|entry| entry.name() == name)
+    }
+
+    /// Largest art in rows and columns that still fits the place it is drawn in,
+    /// marks have to fit inside a board tile and faces inside the status panel.
+    pub fn max_size(&self) -> (usize, usize){
+        match self {
+            ArtEntry::X | ArtEntry::O => (4, 9),
+            ArtEntry::XLarge | ArtEntry::OLarge => (6, 12),
+            ArtEntry::AngryFace | ArtEntry::SmileyFace | ArtEntry::ThinkingFace | ArtEntry::HappyFace => (7, 20),
+            _ => (6, 58),
+        }
+    }
+
+    pub fn get_all() -> [ArtEntry; 23]{
+        [
+            ArtEntry::TicTacToe,
+            ArtEntry::Settings,
+            ArtEntry::YourTurn,
+            ArtEntry::EnemyTurn,
+            ArtEntry::CrossTurn,
+            ArtEntry::CircleTurn,
+            ArtEntry::YouWon,
+            ArtEntry::YouLost,
+            ArtEntry::CrossWon,
+            ArtEntry::CircleWon,
+            ArtEntry::Draw,
+            ArtEntry::X,
+            ArtEntry::O,
+            ArtEntry::XLarge,
+            ArtEntry::OLarge,
+            ArtEntry::AngryFace,
+            ArtEntry::SmileyFace,
+            ArtEntry::ThinkingFace,
+            ArtEntry::HappyFace,
+            ArtEntry::Join,
+            ArtEntry::Lobby,
+            ArtEntry::Players,
+            ArtEntry::Stats,
+        ]
+    }
+}
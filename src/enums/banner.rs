@@ -0,0 +1,29 @@
+/// Text above the board, drawn as art or as a single line on small terminals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Banner{
+    YourTurn,
+    EnemyTurn,
+    CrossTurn,
+    CircleTurn,
+    YouWon,
+    YouLost,
+    CrossWon,
+    CircleWon,
+    Draw,
+}
+
+impl Banner{
+    pub fn as_str(&self) -> &str{
+        match self {
+            Banner::YourTurn => "Your turn",
+            Banner::EnemyTurn => "Enemy turn",
+            Banner::CrossTurn => "Cross turn",
+            Banner::CircleTurn => "Circle turn",
+            Banner::YouWon => "You won",
+            Banner::YouLost => "You lost",
+            Banner::CrossWon => "Cross won",
+            Banner::CircleWon => "Circle won",
+            Banner::Draw => "Draw",
+        }
+    }
+}
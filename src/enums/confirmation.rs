@@ -0,0 +1,19 @@
+use crate::services::locale::tr;
+
+/// Question asked before an action that throws something away.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Confirmation{
+    LeaveGame,
+    RestartGame,
+    ResetSettings,
+}
+
+impl Confirmation{
+    pub fn question(&self) -> &str{
+        match self {
+            Confirmation::LeaveGame => tr("Leave the game? It is not finished yet."),
+            Confirmation::RestartGame => tr("Restart the game? The current one is not finished yet."),
+            Confirmation::ResetSettings => tr("Reset all settings to their defaults?"),
+        }
+    }
+}
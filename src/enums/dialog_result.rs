@@ -0,0 +1,10 @@
+use crate::enums::confirmation::Confirmation;
+use crate::enums::pause_option::PauseOption;
+
+/// Handed to the view below when an overlay closes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DialogResult{
+    Closed,
+    PauseMenu(PauseOption),
+    Confirmed(Confirmation),
+}
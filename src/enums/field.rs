@@ -4,3 +4,23 @@ pub enum FieldMark{
     O,
     Empty
 }
+
+impl FieldMark{
+    /// Single character used when a board is sent to engines or over the network.
+    pub fn as_char(&self) -> char{
+        match self {
+            FieldMark::X => 'x',
+            FieldMark::O => 'o',
+            FieldMark::Empty => '.',
+        }
+    }
+
+    pub fn from_char(character: char) -> Option<FieldMark>{
+        match character {
+            'x' => Some(FieldMark::X),
+            'o' => Some(FieldMark::O),
+            '.' => Some(FieldMark::Empty),
+            _ => None,
+        }
+    }
+}
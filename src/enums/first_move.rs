@@ -0,0 +1,61 @@
+use rand::random_bool;
+use crate::services::locale::tr;
+
+/// Side that plays cross and moves first against the AI or an engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FirstMove{
+    Player,
+    Opponent,
+    Random,
+}
+
+impl FirstMove{
+    pub fn as_str(&self) -> &str{
+        match self {
+            FirstMove::Player => "Player",
+            FirstMove::Opponent => "Opponent",
+            FirstMove::Random => "Random",
+        }
+    }
+
+    pub fn label(&self) -> &str{
+        match self {
+            FirstMove::Player => tr("You"),
+            FirstMove::Opponent => tr("Opponent"),
+            FirstMove::Random => tr("Random"),
+        }
+    }
+
+    pub fn from_str(text: &str) -> Option<FirstMove>{
+        FirstMove::get_all().into_iter().find(|first_move| first_move.as_str() == text)
+    }
+
+    pub fn get_all() -> [FirstMove; 3]{
+        [FirstMove::Player, FirstMove::Opponent, FirstMove::Random]
+    }
+
+    pub fn next(&self) -> FirstMove{
+        match self {
+            FirstMove::Player => FirstMove::Opponent,
+            FirstMove::Opponent => FirstMove::Random,
+            FirstMove::Random => FirstMove::Player,
+        }
+    }
+
+    pub fn previous(&self) -> FirstMove{
+        match self {
+            FirstMove::Player => FirstMove::Random,
+            FirstMove::Opponent => FirstMove::Player,
+            FirstMove::Random => FirstMove::Opponent,
+        }
+    }
+
+    /// Decides a random first move anew on every call.
+    pub fn player_starts(&self) -> bool{
+        match self {
+            FirstMove::Player => true,
+            FirstMove::Opponent => false,
+            FirstMove::Random => random_bool(0.5),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::services::locale::{tr, tr_args};
+
+/// What a key does, views react to actions so the keys behind them can be rebound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAction{
+    Up,
+    Down,
+    Left,
+    Right,
+    Confirm,
+    Back,
+    /// Opens the log of messages shown as notifications, works on every screen.
+    Messages,
+    /// Shows the keys of the current screen.
+    Help,
+    /// Places a mark on the tile directly, tiles are numbered 0-8 from the top left.
+    Tile(u8),
+}
+
+impl KeyAction{
+    /// Name used in the saved keymap.
+    pub fn name(&self) -> String{
+        match self {
+            KeyAction::Up => "up".to_string(),
+            KeyAction::Down => "down".to_string(),
+            KeyAction::Left => "left".to_string(),
+            KeyAction::Right => "right".to_string(),
+            KeyAction::Confirm => "confirm".to_string(),
+            KeyAction::Back => "back".to_string(),
+            KeyAction::Messages => "messages".to_string(),
+            KeyAction::Help => "help".to_string(),
+            KeyAction::Tile(index) => format!("tile{}", index + 1),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<KeyAction>{
+        if let Some(number) = name.strip_prefix("tile") {
+            let number: u8 = number.parse().ok()?;
+            return if (1..=9).contains(&number) {Some(KeyAction::Tile(number - 1))} else {None};
+        }
+        KeyAction::get_rebindable().into_iter().find(|action| action.name() == name)
+    }
+
+    pub fn description(&self) -> String{
+        match self {
+            KeyAction::Up => tr("Move up").to_string(),
+            KeyAction::Down => tr("Move down").to_string(),
+            KeyAction::Left => tr("Move left").to_string(),
+            KeyAction::Right => tr("Move right").to_string(),
+            KeyAction::Confirm => tr("Confirm").to_string(),
+            KeyAction::Back => tr("Go back").to_string(),
+            KeyAction::Messages => tr("Message log").to_string(),
+            KeyAction::Help => tr("Help").to_string(),
+            KeyAction::Tile(index) => tr_args("Tile {}", &[&(index + 1)]),
+        }
+    }
+
+    /// Actions that can be rebound one by one in the settings, direct tile keys come from the numpad preset.
+    pub fn get_rebindable() -> [KeyAction; 8]{
+        [KeyAction::Up, KeyAction::Down, KeyAction::Left, KeyAction::Right, KeyAction::Confirm, KeyAction::Back, KeyAction::Messages, KeyAction::Help]
+    }
+}
@@ -0,0 +1,63 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language{
+    English,
+    Polish,
+}
+
+impl Language{
+    /// Name in the language itself, so everyone finds their own in the list.
+    pub fn as_str(&self) -> &str{
+        match self {
+            Language::English => "English",
+            Language::Polish => "Polski",
+        }
+    }
+
+    /// Code saved in the settings file, also the prefix of matching locales like `pl_PL.UTF-8`.
+    pub fn code(&self) -> &'static str{
+        match self {
+            Language::English => "en",
+            Language::Polish => "pl",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Language>{
+        Language::get_all().into_iter().find(|language| language.code() == code)
+    }
+
+    /// Language of a locale value like `pl_PL.UTF-8` or `en`, `C` and `POSIX` are English.
+    pub fn from_locale(locale: &str) -> Option<Language>{
+        if locale == "C" || locale == "POSIX" {
+            return Some(Language::English);
+        }
+        let code = locale.split(['_', '.', '@', '-']).next()?;
+        Language::from_code(&code.to_lowercase())
+    }
+
+    /// Language of the environment, the first set variable of LC_ALL, LC_MESSAGES and LANG decides like in other programs.
+    pub fn from_environment() -> Language{
+        ["LC_ALL", "LC_MESSAGES", "LANG"].into_iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| Language::from_locale(&value))
+            .unwrap_or(Language::English)
+    }
+
+    pub fn get_all() -> [Language; 2]{
+        [Language::English, Language::Polish]
+    }
+
+    pub fn next(&self) -> Language{
+        match self {
+            Language::English => Language::Polish,
+            Language::Polish => Language::English,
+        }
+    }
+
+    pub fn previous(&self) -> Language{
+        match self {
+            Language::English => Language::Polish,
+            Language::Polish => Language::English,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use ratatui::layout::Rect;
+
+/// Below this width the game view hides its side panels.
+pub const SIDE_PANEL_MIN_WIDTH: u16 = 80;
+/// Smallest terminal the compact layout still fits into.
+pub const MIN_WIDTH: u16 = 24;
+pub const MIN_HEIGHT: u16 = 14;
+
+/// How much room the terminal offers, views pick art and board size based on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutSize{
+    Compact,
+    Normal,
+    Large,
+}
+
+impl LayoutSize{
+    pub fn for_area(area: Rect) -> LayoutSize{
+        if area.width < 60 || area.height < 30 {
+            LayoutSize::Compact
+        }else if area.width >= 120 && area.height >= 40 {
+            LayoutSize::Large
+        }else{
+            LayoutSize::Normal
+        }
+    }
+
+    pub fn is_too_small(area: Rect) -> bool{
+        area.width < MIN_WIDTH || area.height < MIN_HEIGHT
+    }
+}
@@ -1,4 +1,20 @@
 pub mod view_action;
 pub mod field;
 pub mod player;
-pub mod player_type;
\ No newline at end of file
+pub mod player_type;
+pub mod outcome;
+pub mod ai_level;
+pub mod key_action;
+pub mod layout_size;
+pub mod banner;
+pub mod theme_element;
+pub mod art_entry;
+pub mod app_event;
+pub mod notification_kind;
+pub mod pause_option;
+pub mod confirmation;
+pub mod dialog_result;
+pub mod language;
+pub mod situation;
+pub mod variant;
+pub mod first_move;
@@ -0,0 +1,18 @@
+use crate::services::locale::tr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationKind{
+    Info,
+    Success,
+    Error,
+}
+
+impl NotificationKind{
+    pub fn as_str(&self) -> &str{
+        match self {
+            NotificationKind::Info => tr("Info"),
+            NotificationKind::Success => tr("Done"),
+            NotificationKind::Error => tr("Error"),
+        }
+    }
+}
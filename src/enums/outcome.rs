@@ -0,0 +1,17 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome{
+    Win,
+    Loss,
+    Draw,
+}
+
+impl Outcome{
+    /// Points used by the rating system.
+    pub fn score(&self) -> f64{
+        match self {
+            Outcome::Win => 1.0,
+            Outcome::Draw => 0.5,
+            Outcome::Loss => 0.0,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,32 @@
+use crate::enums::player_type::PlayerType;
+use crate::services::locale::tr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseOption{
+    Resume,
+    Restart,
+    Save,
+    Settings,
+    QuitToMenu,
+}
+
+impl PauseOption{
+    pub fn as_str(&self) -> &str{
+        match self {
+            PauseOption::Resume => tr("Resume"),
+            PauseOption::Restart => tr("Restart"),
+            PauseOption::Save => tr("Save"),
+            PauseOption::Settings => tr("Settings"),
+            PauseOption::QuitToMenu => tr("Quit to menu"),
+        }
+    }
+
+    /// Network games can not be restarted or saved, the other side owns half of the game.
+    pub fn get_for(opponent_type: PlayerType) -> Vec<PauseOption>{
+        if opponent_type == PlayerType::Remote {
+            vec![PauseOption::Resume, PauseOption::Settings, PauseOption::QuitToMenu]
+        }else{
+            vec![PauseOption::Resume, PauseOption::Restart, PauseOption::Save, PauseOption::Settings, PauseOption::QuitToMenu]
+        }
+    }
+}
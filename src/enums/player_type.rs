@@ -1,5 +1,26 @@
 #[derive(Debug, Clone, Copy,PartialEq, Eq)]
 pub enum PlayerType {
     Ai,
-    Human
-}
\ No newline at end of file
+    Human,
+    Engine,
+    Remote
+}
+
+impl PlayerType{
+    pub fn as_str(&self) -> &str{
+        match self {
+            PlayerType::Ai => "Ai",
+            PlayerType::Human => "Human",
+            PlayerType::Engine => "Engine",
+            PlayerType::Remote => "Remote",
+        }
+    }
+
+    pub fn from_str(text: &str) -> Option<PlayerType>{
+        PlayerType::get_all().into_iter().find(|player_type| player_type.as_str() == text)
+    }
+
+    pub fn get_all() -> [PlayerType; 4]{
+        [PlayerType::Ai, PlayerType::Human, PlayerType::Engine, PlayerType::Remote]
+    }
+}
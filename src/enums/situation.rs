@@ -0,0 +1,46 @@
+use crate::enums::art_entry::ArtEntry;
+
+/// Moment of the game the AI comments on, personalities have a pool of lines for each.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Situation{
+    Waiting,
+    Thinking,
+    Blocking,
+    Blunder,
+    Winning,
+    Losing,
+    Draw,
+}
+
+impl Situation{
+    /// Row name in personality files.
+    pub fn as_str(&self) -> &str{
+        match self {
+            Situation::Waiting => "waiting",
+            Situation::Thinking => "thinking",
+            Situation::Blocking => "blocking",
+            Situation::Blunder => "blunder",
+            Situation::Winning => "winning",
+            Situation::Losing => "losing",
+            Situation::Draw => "draw",
+        }
+    }
+
+    pub fn from_str(text: &str) -> Option<Situation>{
+        Situation::get_all().into_iter().find(|situation| situation.as_str() == text)
+    }
+
+    pub fn get_all() -> [Situation; 7]{
+        [Situation::Waiting, Situation::Thinking, Situation::Blocking, Situation::Blunder, Situation::Winning, Situation::Losing, Situation::Draw]
+    }
+
+    /// Face the AI makes, the art comes from the personality's faces.
+    pub fn face(&self) -> ArtEntry{
+        match self {
+            Situation::Waiting => ArtEntry::SmileyFace,
+            Situation::Thinking => ArtEntry::ThinkingFace,
+            Situation::Blocking | Situation::Blunder | Situation::Winning => ArtEntry::HappyFace,
+            Situation::Losing | Situation::Draw => ArtEntry::AngryFace,
+        }
+    }
+}
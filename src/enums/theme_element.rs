@@ -0,0 +1,56 @@
+use crate::services::locale::tr;
+
+/// Part of the interface that gets its own color in a theme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeElement{
+    Player,
+    Opponent,
+    Text,
+    Background,
+    Border,
+    Highlight,
+    Accent,
+    Banner,
+    Win,
+    Loss,
+}
+
+impl ThemeElement{
+    pub fn as_str(&self) -> &str{
+        match self {
+            ThemeElement::Player => "Player",
+            ThemeElement::Opponent => "Opponent",
+            ThemeElement::Text => "Text",
+            ThemeElement::Background => "Background",
+            ThemeElement::Border => "Borders",
+            ThemeElement::Highlight => "Highlight",
+            ThemeElement::Accent => "Accent",
+            ThemeElement::Banner => "Banners",
+            ThemeElement::Win => "Win",
+            ThemeElement::Loss => "Loss",
+        }
+    }
+
+    pub fn label(&self) -> &str{
+        tr(self.as_str())
+    }
+
+    pub fn from_str(text: &str) -> Option<ThemeElement>{
+        ThemeElement::get_all().into_iter().find(|element| element.as_str() == text)
+    }
+
+    pub fn get_all() -> [ThemeElement; 10]{
+        [
+            ThemeElement::Player,
+            ThemeElement::Opponent,
+            ThemeElement::Text,
+            ThemeElement::Background,
+            ThemeElement::Border,
+            ThemeElement::Highlight,
+            ThemeElement::Accent,
+            ThemeElement::Banner,
+            ThemeElement::Win,
+            ThemeElement::Loss,
+        ]
+    }
+}
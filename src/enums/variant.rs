@@ -0,0 +1,44 @@
+use crate::services::locale::tr;
+
+/// Rules of a local or AI game, network and engine games are always classic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant{
+    Classic,
+    /// Completing a line loses the game.
+    Misere,
+}
+
+impl Variant{
+    pub fn as_str(&self) -> &str{
+        match self {
+            Variant::Classic => "Classic",
+            Variant::Misere => "Misere",
+        }
+    }
+
+    pub fn label(&self) -> &str{
+        match self {
+            Variant::Classic => tr("Classic"),
+            Variant::Misere => tr("Misère"),
+        }
+    }
+
+    pub fn from_str(text: &str) -> Option<Variant>{
+        Variant::get_all().into_iter().find(|variant| variant.as_str() == text)
+    }
+
+    pub fn get_all() -> [Variant; 2]{
+        [Variant::Classic, Variant::Misere]
+    }
+
+    pub fn next(&self) -> Variant{
+        match self {
+            Variant::Classic => Variant::Misere,
+            Variant::Misere => Variant::Classic,
+        }
+    }
+
+    pub fn previous(&self) -> Variant{
+        self.next()
+    }
+}
@@ -1,13 +1,35 @@
-use ratatui::style::Color;
+use std::net::SocketAddr;
+use crate::services::network::Role;
 use crate::enums::player::Player;
 use crate::enums::player_type::PlayerType;
+use crate::enums::theme_element::ThemeElement;
+use crate::enums::confirmation::Confirmation;
+use crate::enums::dialog_result::DialogResult;
 
+/// What the main loop does with the view stack, `GoTo` and `Open` push a view, `Back` and `Return` close the top one.
 #[derive(Debug, Clone, Copy)]
 pub enum ViewAction {
     GoToMain,
     GoToSettings,
     GoToGame(PlayerType),
-    ChangeColor((Color, Player)),
+    GoToSeats(PlayerType),
+    GoToNewProfile(Player),
+    GoToJoin,
+    GoToLobby,
+    GoToStatistics,
+    OpenThemeColors,
+    OpenColorPicker(ThemeElement),
+    OpenKeyBindings,
+    OpenPauseMenu(PlayerType),
+    OpenConfirm(Confirmation),
+    ContinueGame,
+    /// Help overlay with the keys of the view on top.
+    OpenHelp,
+    HostGame,
+    JoinGame(SocketAddr, Role),
+    Back,
+    /// Closes the top view and hands the result to the view below.
+    Return(DialogResult),
     Quit,
     Nothing
 }
\ No newline at end of file
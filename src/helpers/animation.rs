@@ -0,0 +1,45 @@
+use std::time::{Duration, Instant};
+use ratatui::layout::Rect;
+
+/// Time based animation, progress only depends on the time since it started so slow frames skip ahead.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Animation{
+    started: Instant,
+    duration: Duration,
+}
+
+impl Animation{
+    pub fn new(started: Instant, duration: Duration) -> Animation{
+        Animation{ started, duration }
+    }
+
+    /// Progress from 0.0 to 1.0, eased out so movement slows down at the end.
+    pub fn progress(&self, now: Instant) -> f64{
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        let linear = (now.saturating_duration_since(self.started).as_secs_f64() / self.duration.as_secs_f64()).min(1.0);
+        1.0 - (1.0 - linear).powi(2)
+    }
+
+    pub fn is_finished(&self, now: Instant) -> bool{
+        now.saturating_duration_since(self.started) >= self.duration
+    }
+
+    /// Alternates between true and false every `period` while the animation runs, false afterwards.
+    pub fn flash(&self, now: Instant, period: Duration) -> bool{
+        if self.is_finished(now) || period.is_zero() {
+            return false;
+        }
+        let elapsed = now.saturating_duration_since(self.started).as_millis();
+        (elapsed / period.as_millis()).is_multiple_of(2)
+    }
+}
+
+/// Part of `target` visible while sliding in from the right edge of `bounds`.
+pub fn slide_in(target: Rect, bounds: Rect, progress: f64) -> Rect{
+    let start = bounds.right().saturating_sub(1).max(target.x);
+    let x = start - ((start - target.x) as f64 * progress).round() as u16;
+    let width = target.width.min(bounds.right().saturating_sub(x));
+    Rect { x, width, ..target }
+}
@@ -0,0 +1,97 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use crate::enums::key_action::KeyAction;
+use crate::enums::view_action::ViewAction;
+use crate::services::keymap;
+use crate::services::locale::tr;
+use crate::services::keymap::KeyMap;
+
+/// What a binding reacts to, an action of the keymap, any direct tile key or a fixed key.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Trigger{
+    Action(KeyAction),
+    Tiles,
+    /// Fixed keys are for text inputs and letters that are not worth rebinding, letters match both cases.
+    Key(KeyCode),
+}
+
+/// One row of a view's key table, the same table handles the keys and describes them in the help.
+pub struct Binding<V>{
+    pub trigger: Trigger,
+    pub description: &'static str,
+    pub run: fn(&mut V, KeyEvent) -> ViewAction,
+}
+
+impl<V> Binding<V>{
+    pub fn action(action: KeyAction, description: &'static str, run: fn(&mut V, KeyEvent) -> ViewAction) -> Binding<V>{
+        Binding{ trigger: Trigger::Action(action), description, run }
+    }
+
+    pub fn tiles(description: &'static str, run: fn(&mut V, KeyEvent) -> ViewAction) -> Binding<V>{
+        Binding{ trigger: Trigger::Tiles, description, run }
+    }
+
+    pub fn key(key: KeyCode, description: &'static str, run: fn(&mut V, KeyEvent) -> ViewAction) -> Binding<V>{
+        Binding{ trigger: Trigger::Key(key), description, run }
+    }
+
+    /// The help key, every view has it so the help overlay can be opened anywhere.
+    pub fn help() -> Binding<V>{
+        Binding::action(KeyAction::Help, "Show keys", |_, _| ViewAction::OpenHelp)
+    }
+
+    //In text inputs `?` is typed, so only F1 opens the help there
+    pub fn typing_help() -> Binding<V>{
+        Binding::key(KeyCode::F(1), "Show keys", |_, _| ViewAction::OpenHelp)
+    }
+
+    fn matches(&self, action: Option<KeyAction>, key: KeyEvent) -> bool{
+        match (self.trigger, key.code) {
+            (Trigger::Action(bound), _) => action == Some(bound),
+            (Trigger::Tiles, _) => matches!(action, Some(KeyAction::Tile(_))),
+            (Trigger::Key(KeyCode::Char(bound)), KeyCode::Char(pressed)) => bound.eq_ignore_ascii_case(&pressed),
+            (Trigger::Key(bound), pressed) => bound == pressed,
+        }
+    }
+}
+
+/// Row of the help overlay, bindings with the same description share a row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HelpEntry{
+    pub keys: String,
+    pub description: String,
+}
+
+/// Runs the first binding matching the key, None when the table has no binding for it.
+pub fn dispatch<V>(view: &mut V, bindings: &[Binding<V>], action: Option<KeyAction>, key: KeyEvent) -> Option<ViewAction>{
+    let binding = bindings.iter().find(|binding| binding.matches(action, key))?;
+    Some((binding.run)(view, key))
+}
+
+/// Help rows with the descriptions in the active language.
+pub fn help_entries<V>(bindings: &[Binding<V>], keymap: &KeyMap) -> Vec<HelpEntry>{
+    let mut entries: Vec<HelpEntry> = Vec::new();
+    for binding in bindings {
+        let keys = trigger_label(binding.trigger, keymap);
+        if keys.is_empty() {
+            continue;
+        }
+        let description = tr(binding.description);
+        match entries.iter_mut().find(|entry| entry.description == description) {
+            Some(entry) => entry.keys = format!("{},{}", entry.keys, keys),
+            None => entries.push(HelpEntry { keys, description: description.to_string() }),
+        }
+    }
+    entries
+}
+
+//Empty when nothing is bound, e.g. tiles without the numpad preset
+fn trigger_label(trigger: Trigger, keymap: &KeyMap) -> String{
+    match trigger {
+        Trigger::Action(action) => keymap.keys_for(action).into_iter().map(keymap::key_label).collect::<Vec<String>>().join(","),
+        Trigger::Tiles => (0..9)
+            .filter_map(|index| keymap.keys_for(KeyAction::Tile(index)).first().map(|key| keymap::key_label(*key)))
+            .collect(),
+        Trigger::Key(KeyCode::Char(character)) => character.to_ascii_uppercase().to_string(),
+        Trigger::Key(key) => keymap::key_label(key),
+    }
+}
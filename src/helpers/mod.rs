@@ -1 +1,7 @@
-pub mod vector_helper;
\ No newline at end of file
+pub mod vector_helper;
+pub mod text_input;
+pub mod mouse;
+pub mod animation;
+pub mod notifications;
+pub mod key_table;
+pub mod setting_widget;
\ No newline at end of file
@@ -0,0 +1,16 @@
+use ratatui::layout::{Position, Rect};
+use ratatui::widgets::ListState;
+
+/// Index of the list item under the cursor, for a list drawn into `area` with one line per item.
+pub fn list_item_at(area: Rect, state: &ListState, item_count: usize, column: u16, row: u16) -> Option<usize>{
+    if !area.contains(Position::new(column, row)) {
+        return None;
+    }
+    let index = state.offset() + (row - area.y) as usize;
+    if index < item_count {Some(index)} else {None}
+}
+
+/// Index of the rect under the cursor, e.g. a board tile.
+pub fn rect_at(rects: &[Rect], column: u16, row: u16) -> Option<usize>{
+    rects.iter().position(|rect| rect.contains(Position::new(column, row)))
+}
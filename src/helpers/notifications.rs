@@ -0,0 +1,184 @@
+use std::time::{Duration, Instant};
+use ratatui::crossterm::event::KeyEvent;
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Constraint, Margin, Rect};
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, BorderType, Clear, List, ListState, Paragraph, Wrap};
+use crate::enums::key_action::KeyAction;
+use crate::enums::notification_kind::NotificationKind;
+use crate::services::keymap::KeyMap;
+use crate::services::locale::{tr, tr_args};
+use crate::services::theme::Theme;
+
+const MAX_LOG_LENGTH: usize = 100;
+const MAX_TOASTS: usize = 3;
+const TOAST_WIDTH: u16 = 40;
+
+/// Message for the player, shown as a toast and kept in the message log.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification{
+    pub kind: NotificationKind,
+    pub text: String,
+    pub created: Instant,
+}
+
+impl Notification{
+    pub fn info(text: impl Into<String>) -> Notification{
+        Notification{ kind: NotificationKind::Info, text: text.into(), created: Instant::now() }
+    }
+
+    pub fn success(text: impl Into<String>) -> Notification{
+        Notification{ kind: NotificationKind::Success, text: text.into(), created: Instant::now() }
+    }
+
+    pub fn error(text: impl Into<String>) -> Notification{
+        Notification{ kind: NotificationKind::Error, text: text.into(), created: Instant::now() }
+    }
+
+    /// Errors stay a bit longer, they usually need to be read.
+    fn lifetime(&self) -> Duration{
+        match self.kind {
+            NotificationKind::Error => Duration::from_secs(5),
+            _ => Duration::from_secs(3),
+        }
+    }
+
+    fn style(&self, theme: &Theme) -> Style{
+        match self.kind {
+            NotificationKind::Info => theme.accent_style(),
+            NotificationKind::Success => theme.win_style(),
+            NotificationKind::Error => theme.loss_style(),
+        }
+    }
+}
+
+/// Notifications of all views, drawn by the main loop on top of the current view.
+pub struct Notifications{
+    log: Vec<Notification>,
+    log_open: bool,
+    log_list: ListState,
+    shown_toasts: usize,
+}
+
+impl Notifications{
+    pub fn new() -> Notifications{
+        Notifications{ log: Vec::new(), log_open: false, log_list: ListState::default(), shown_toasts: 0 }
+    }
+
+    pub fn push(&mut self, notification: Notification){
+        self.log.push(notification);
+        if self.log.len() > MAX_LOG_LENGTH {
+            self.log.remove(0);
+        }
+    }
+
+    /// True when a toast expired since the last render, the screen has to be redrawn without it.
+    pub fn toasts_changed(&self, now: Instant) -> bool{
+        !self.log_open && self.toasts(now).count() != self.shown_toasts
+    }
+
+    //Newest first, toasts disappear on their own after their lifetime
+    fn toasts(&self, now: Instant) -> impl Iterator<Item = &Notification>{
+        self.log.iter().rev()
+            .take(MAX_TOASTS)
+            .filter(move |notification| now.saturating_duration_since(notification.created) < notification.lifetime())
+    }
+
+    /// Handles the message log key everywhere and all keys while the log is open, returns true when the key was used.
+    pub fn handle_key(&mut self, key: KeyEvent, keymap: &KeyMap) -> bool{
+        let action = keymap.action_for(key);
+        if !self.log_open {
+            if action == Some(KeyAction::Messages) {
+                self.log_open = true;
+                self.log_list.select(self.log.len().checked_sub(1));
+                return true;
+            }
+            return false;
+        }
+
+        match action {
+            Some(KeyAction::Messages) | Some(KeyAction::Back) => self.log_open = false,
+            Some(KeyAction::Up) => self.log_list.select_previous(),
+            Some(KeyAction::Down) => self.log_list.select_next(),
+            _ => {}
+        }
+        true
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, theme: &Theme, keymap: &KeyMap, now: Instant){
+        if self.log_open {
+            self.render_log(frame, theme, keymap, now);
+            return;
+        }
+        self.render_toasts(frame, theme, now);
+        self.render_status(frame, keymap);
+    }
+
+    fn render_toasts(&mut self, frame: &mut Frame, theme: &Theme, now: Instant){
+        self.shown_toasts = self.toasts(now).count();
+        let area = frame.area().inner(Margin::new(2,1));
+        let width = TOAST_WIDTH.min(area.width);
+        let mut y = area.y;
+
+        for notification in self.toasts(now) {
+            let paragraph = Paragraph::new(notification.text.as_str()).wrap(Wrap { trim: true });
+            let text_width = width.saturating_sub(2).max(1) as usize;
+            let lines = notification.text.chars().count().div_ceil(text_width).max(1) as u16;
+            let height = (lines + 2).min(area.bottom().saturating_sub(y));
+            if height < 3 {
+                break;
+            }
+            let toast_area = Rect { x: area.right() - width, y, width, height };
+            let block = Block::bordered()
+                .border_type(BorderType::Rounded)
+                .border_style(notification.style(theme))
+                .style(theme.base_style())
+                .title(notification.kind.as_str());
+            frame.render_widget(Clear, toast_area);
+            frame.render_widget(paragraph.block(block), toast_area);
+            y += height;
+        }
+    }
+
+    //Hint on the bottom border that there are messages to read
+    fn render_status(&self, frame: &mut Frame, keymap: &KeyMap){
+        if self.log.is_empty() {
+            return;
+        }
+        let text = format!(" {} ", tr_args("{} - messages ({})", &[&keymap.label_for(KeyAction::Messages), &self.log.len()]));
+        let area = frame.area();
+        let width = (text.chars().count() as u16).min(area.width.saturating_sub(4));
+        let status_area = Rect { x: area.right().saturating_sub(width + 2), y: area.bottom().saturating_sub(1), width, height: 1 };
+        frame.render_widget(Paragraph::new(text), status_area);
+    }
+
+    fn render_log(&mut self, frame: &mut Frame, theme: &Theme, keymap: &KeyMap, now: Instant){
+        let popup_area = frame.area().centered(Constraint::Percentage(70),Constraint::Percentage(70));
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(theme.border_style())
+            .style(theme.base_style())
+            .title(tr("Messages"))
+            .title_bottom(tr_args("{},{} - scroll, {} - close", &[&keymap.label_for(KeyAction::Up), &keymap.label_for(KeyAction::Down), &keymap.label_for(KeyAction::Back)]))
+            .title_alignment(Alignment::Center);
+        frame.render_widget(Clear, popup_area);
+
+        if self.log.is_empty() {
+            frame.render_widget(Paragraph::new(tr("No messages yet")).centered().block(block), popup_area);
+            return;
+        }
+        let items = self.log.iter().map(|notification| {
+            let age = now.saturating_duration_since(notification.created).as_secs();
+            Line::from(vec![
+                Span::styled(format!("{:<6}", notification.kind.as_str()), notification.style(theme)),
+                Span::raw(format!("{:>10}  ", tr_args("{}s ago", &[&age]))),
+                Span::raw(notification.text.clone()),
+            ])
+        }).collect::<Vec<Line>>();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(theme.highlight_style());
+        frame.render_stateful_widget(list, popup_area, &mut self.log_list);
+    }
+}
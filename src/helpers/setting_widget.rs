@@ -0,0 +1,60 @@
+use ratatui::style::Style;
+use ratatui::text::Span;
+use crate::services::locale::tr;
+
+const SLIDER_WIDTH: u32 = 12;
+
+/// Value part of a settings row, drawn after the label.
+pub enum SettingWidget{
+    Toggle(bool),
+    /// Bar filled by the share of `value` between `min` and `max`, followed by `text`.
+    Slider{ value: u32, min: u32, max: u32, text: String },
+    /// One value out of a list, changed with left and right.
+    Choice(String),
+    /// Free text edited in a text field after Enter.
+    Text(String),
+    /// Opens another screen or asks before doing something.
+    Action,
+}
+
+impl SettingWidget{
+    /// Plain text leaves out the bars and arrows, screen readers only read the value.
+    pub fn spans(&self, accent: Style, plain_text: bool) -> Vec<Span<'static>>{
+        match self {
+            SettingWidget::Toggle(on) => {
+                let state = tr(if *on {"On"} else {"Off"}).to_string();
+                if plain_text {
+                    return vec![Span::raw(state)];
+                }
+                vec![Span::styled(if *on {"[x]"} else {"[ ]"}, accent), Span::raw(format!(" {}", state))]
+            }
+            SettingWidget::Slider{ value, min, max, text } => {
+                if plain_text {
+                    return vec![Span::raw(text.clone())];
+                }
+                let filled = (value.clamp(min, max) - min) * SLIDER_WIDTH / (max - min).max(1);
+                vec![
+                    Span::raw("◀ "),
+                    Span::styled("█".repeat(filled as usize), accent),
+                    Span::raw("░".repeat((SLIDER_WIDTH - filled) as usize)),
+                    Span::raw(format!(" ▶ {}", text)),
+                ]
+            }
+            SettingWidget::Choice(value) => {
+                if plain_text {
+                    return vec![Span::raw(value.clone())];
+                }
+                vec![Span::raw("◀ "), Span::styled(value.clone(), accent), Span::raw(" ▶")]
+            }
+            SettingWidget::Text(value) => {
+                if plain_text {
+                    return vec![Span::raw(value.clone())];
+                }
+                vec![Span::styled(value.clone(), accent), Span::raw(" ›")]
+            }
+            SettingWidget::Action => {
+                if plain_text {Vec::new()} else {vec![Span::styled("›", accent)]}
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, BorderType, Paragraph};
+use ratatui::Frame;
+
+/// Single line text field, the view owning it decides what Enter and Esc do.
+pub struct TextInput{
+    value: String,
+    cursor: usize,
+    max_length: usize,
+}
+
+impl TextInput{
+    pub fn new(value: &str, max_length: usize) -> TextInput{
+        TextInput{
+            value: value.to_string(),
+            cursor: value.chars().count(),
+            max_length,
+        }
+    }
+
+    pub fn value(&self) -> &str{
+        &self.value
+    }
+
+    /// Returns true when the key was consumed by the field.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool{
+        match key.code {
+            KeyCode::Char(character) => {
+                if self.value.chars().count() < self.max_length {
+                    let byte_index = self.byte_index();
+                    self.value.insert(byte_index, character);
+                    self.cursor += 1;
+                }
+            }
+            KeyCode::Backspace => {
+                if self.cursor > 0 {
+                    self.cursor -= 1;
+                    let byte_index = self.byte_index();
+                    self.value.remove(byte_index);
+                }
+            }
+            KeyCode::Delete => {
+                if self.cursor < self.value.chars().count() {
+                    let byte_index = self.byte_index();
+                    self.value.remove(byte_index);
+                }
+            }
+            KeyCode::Left => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right => self.cursor = (self.cursor + 1).min(self.value.chars().count()),
+            KeyCode::Home => self.cursor = 0,
+            KeyCode::End => self.cursor = self.value.chars().count(),
+            _ => return false,
+        }
+        true
+    }
+
+    fn byte_index(&self) -> usize{
+        self.value.char_indices().nth(self.cursor).map(|(index, _)| index).unwrap_or(self.value.len())
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, title: &str){
+        let (before, after) = self.value.split_at(self.byte_index());
+        let mut after_chars = after.chars();
+        let under_cursor = after_chars.next().map(String::from).unwrap_or(" ".to_string());
+
+        let line = Line::from(vec![
+            Span::raw(before.to_string()),
+            Span::styled(under_cursor, Style::new().reversed()),
+            Span::raw(after_chars.collect::<String>()),
+        ]);
+
+        let block = Block::bordered().border_type(BorderType::Rounded).title(title.to_string());
+        frame.render_widget(Paragraph::new(line).block(block), area);
+    }
+}
@@ -3,86 +3,354 @@ mod services;
 mod traits;
 mod enums;
 mod helpers;
+#[cfg(test)]
+mod tests;
 
 use std::cell::RefCell;
+use std::net::SocketAddr;
+use std::panic;
+use std::path::PathBuf;
 use std::rc::Rc;
-use std::thread;
-use std::time::Duration;
-use ratatui::style::{Color};
+use std::time::{Duration, Instant};
+use ratatui::crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+use ratatui::crossterm::{cursor, execute};
+use ratatui::{DefaultTerminal, Frame};
+use ratatui::widgets::Block;
+use crate::enums::app_event::AppEvent;
 use crate::enums::player::Player;
 use crate::enums::player_type::PlayerType;
+use crate::enums::theme_element::ThemeElement;
+use crate::enums::confirmation::Confirmation;
+use crate::enums::dialog_result::DialogResult;
 use crate::enums::view_action::ViewAction;
+use crate::helpers::notifications::{Notification, Notifications};
+use crate::services::art_pack::ArtPack;
+use crate::services::cli;
+use crate::services::crash_report;
+use crate::services::event_loop::EventLoop;
+use crate::services::game_art as Art;
+use crate::services::keymap::KeyMap;
+use crate::services::locale;
+use crate::services::locale::tr_args;
+use crate::services::network::Role;
+use crate::services::profiles::ProfileStore;
+use crate::services::saved_game;
 use crate::services::settings::Settings;
+use crate::services::theme::Theme;
 use crate::traits::view_model::ViewModel;
 use crate::views::game_view::AiGameView;
+use crate::views::join_view::JoinView;
+use crate::views::lobby_view::LobbyView;
 use crate::views::main_view::MainView;
+use crate::views::new_profile_view::NewProfileView;
+use crate::views::seats_view::SeatsView;
 use crate::views::settings_view::SettingsView;
+use crate::views::statistics_view::StatisticsView;
+use crate::views::theme_colors_view::ThemeColorsView;
+use crate::views::color_picker_view::ColorPickerView;
+use crate::views::key_bindings_view::KeyBindingsView;
+use crate::views::pause_view::PauseView;
+use crate::views::confirm_view::ConfirmView;
+use crate::views::help_view::HelpView;
+
+const TICK_RATE: Duration = Duration::from_millis(33);
 
 pub struct AppState{
-    current_view: Box<dyn ViewModel>,
+    //Bottom is always the main menu, the top view gets the input
+    views: Vec<Box<dyn ViewModel>>,
     settings: Rc<RefCell<Settings>>,
+    profiles: Rc<RefCell<ProfileStore>>,
+    notifications: Notifications,
     running: bool,
 }
 
 impl AppState{
-    pub fn new() -> AppState{
-        let settings = Settings::new();
-        
+    pub fn new(mut settings: Settings) -> AppState{
+        let profiles = ProfileStore::load(&settings.data_dir);
+        settings.keymap = KeyMap::load(&settings.data_dir);
+        settings.theme = Theme::load(&settings.data_dir);
+        settings.load_preferences();
+        locale::use_language(settings.language);
+        let art_pack = ArtPack::load_selected(&settings.data_dir);
+        settings.art_pack = art_pack.name.clone();
+        Art::use_pack(art_pack);
+        let settings = Rc::new(RefCell::new(settings));
+
         AppState{
-            current_view: Box::new(MainView::new()),
+            views: vec![Box::new(MainView::new(Rc::clone(&settings)))],
             running: true,
-            settings: Rc::new(RefCell::new(settings))
+            settings,
+            profiles: Rc::new(RefCell::new(profiles)),
+            notifications: Notifications::new(),
         }
         
     }
 
+    pub fn current_view(&mut self) -> &mut dyn ViewModel{
+        self.views.last_mut().expect("the main menu is never closed").as_mut()
+    }
+
     pub fn handle_view_action(&mut self, action: ViewAction){
         match action{
             ViewAction::GoToMain => {self.go_to_main()}
             ViewAction::GoToGame(player_type) => {self.go_to_game_view(player_type)}
+            ViewAction::GoToSeats(player_type) => {self.go_to_seats(player_type)}
+            ViewAction::GoToNewProfile(seat) => {self.go_to_new_profile(seat)}
             ViewAction::GoToSettings => {self.go_to_settings()}
+            ViewAction::GoToJoin => {self.go_to_join()}
+            ViewAction::GoToLobby => {self.go_to_lobby()}
+            ViewAction::GoToStatistics => {self.go_to_statistics()}
+            ViewAction::OpenThemeColors => {self.open_theme_colors()}
+            ViewAction::OpenColorPicker(element) => {self.open_color_picker(element)}
+            ViewAction::OpenKeyBindings => {self.open_key_bindings()}
+            ViewAction::OpenPauseMenu(opponent_type) => {self.open_pause_menu(opponent_type)}
+            ViewAction::OpenConfirm(confirmation) => {self.open_confirm(confirmation)}
+            ViewAction::ContinueGame => {self.continue_saved_game()}
+            ViewAction::OpenHelp => {self.open_help()}
+            ViewAction::HostGame => {self.go_to_host_game()}
+            ViewAction::JoinGame(address, role) => {self.go_to_joined_game(address, role)}
+            ViewAction::Back => {self.pop_view(DialogResult::Closed)}
+            ViewAction::Return(result) => {self.pop_view(result)}
             ViewAction::Quit => {self.running = false}
             ViewAction::Nothing => {},
-            ViewAction::ChangeColor((color,player)) => {self.change_color(color,player)}
         }
     }
 
-    fn change_color(&mut self, color: Color, player: Player) {
-        match player {
-            Player::Player => {self.settings.borrow_mut().change_player_style(color)},
-            Player::Opponent => {self.settings.borrow_mut().change_opponent_style(color)},
+    //Everything above the main menu is closed, the menu keeps its selection
+    fn go_to_main(&mut self){
+        self.views.truncate(1);
+        let action = self.current_view().handle_result(DialogResult::Closed);
+        self.handle_view_action(action);
+    }
+
+    fn go_to_settings(&mut self){
+        let view_mode = SettingsView::new(Rc::clone(&self.settings));
+        self.push_view(view_mode);
+    }
+
+    fn go_to_seats(&mut self, player_type: PlayerType){
+        let view_model = SeatsView::new(player_type, Rc::clone(&self.settings), Rc::clone(&self.profiles));
+        self.push_view(view_model);
+    }
+
+    fn go_to_new_profile(&mut self, seat: Player){
+        let view_model = NewProfileView::new(seat, Rc::clone(&self.profiles), Rc::clone(&self.settings));
+        self.push_view(view_model);
+    }
+
+    fn go_to_join(&mut self){
+        let view_model = JoinView::new(Rc::clone(&self.settings));
+        self.push_view(view_model);
+    }
+
+    fn go_to_lobby(&mut self){
+        let view_model = LobbyView::new(Rc::clone(&self.settings));
+        self.push_view(view_model);
+    }
+
+    fn go_to_statistics(&mut self){
+        let view_model = StatisticsView::new(Rc::clone(&self.settings), Rc::clone(&self.profiles));
+        self.push_view(view_model);
+    }
+
+    fn open_theme_colors(&mut self){
+        let view_model = ThemeColorsView::new(Rc::clone(&self.settings));
+        self.push_view(view_model);
+    }
+
+    fn open_color_picker(&mut self, element: ThemeElement){
+        let view_model = ColorPickerView::new(element, Rc::clone(&self.settings));
+        self.push_view(view_model);
+    }
+
+    fn open_key_bindings(&mut self){
+        let view_model = KeyBindingsView::new(Rc::clone(&self.settings));
+        self.push_view(view_model);
+    }
+
+    fn open_pause_menu(&mut self, opponent_type: PlayerType){
+        let view_model = PauseView::new(opponent_type, Rc::clone(&self.settings));
+        self.push_view(view_model);
+    }
+
+    fn open_confirm(&mut self, confirmation: Confirmation){
+        let view_model = ConfirmView::new(confirmation, Rc::clone(&self.settings));
+        self.push_view(view_model);
+    }
+
+    fn open_help(&mut self){
+        let entries = self.current_view().help();
+        let view_model = HelpView::new(entries, Rc::clone(&self.settings));
+        self.push_view(view_model);
+    }
+
+    //The save is used up, saving again from the pause menu writes a new one
+    fn continue_saved_game(&mut self){
+        let data_dir = self.settings.borrow().data_dir.clone();
+        let Some(saved) = saved_game::load(&data_dir) else { return };
+        let view_model = AiGameView::from_saved(&saved, Rc::clone(&self.settings), Rc::clone(&self.profiles));
+        if let Err(error) = saved_game::remove(&data_dir) {
+            self.notifications.push(Notification::error(tr_args("Could not remove saved game: {}", &[&error])));
         }
+        self.push_view(view_model);
     }
 
-    fn go_to_main(&mut self){
-        let view_model = MainView::new();
-        self.current_view = Box::new(view_model);
+    fn go_to_host_game(&mut self){
+        let view_model = AiGameView::host(Rc::clone(&self.settings), Rc::clone(&self.profiles));
+        self.push_view(view_model);
     }
 
-    fn go_to_settings(&mut self){
-        let view_mode = SettingsView::new();
-        self.current_view = Box::new(view_mode);
+    //The join screen is done once connected, leaving the game skips it
+    fn go_to_joined_game(&mut self, address: SocketAddr, role: Role){
+        let view_model = AiGameView::join(address, role, Rc::clone(&self.settings), Rc::clone(&self.profiles));
+        self.replace_view(view_model);
     }
 
     fn go_to_game_view(&mut self, player_type: PlayerType){
-        let view_model = AiGameView::new(player_type, Rc::clone(&self.settings));
-        self.current_view = Box::new(view_model);
+        let view_model = AiGameView::new(player_type, Rc::clone(&self.settings), Rc::clone(&self.profiles));
+        self.push_view(view_model);
+    }
+
+    fn push_view(&mut self, view: impl ViewModel + 'static){
+        self.views.push(Box::new(view));
+    }
+
+    /// Closes the top view and hands `result` to the view below, closing the main menu quits.
+    fn pop_view(&mut self, result: DialogResult){
+        if self.views.len() > 1 {
+            self.views.pop();
+            let action = self.current_view().handle_result(result);
+            self.handle_view_action(action);
+        }else{
+            self.running = false;
+        }
+    }
+
+    fn replace_view(&mut self, view: impl ViewModel + 'static){
+        if self.views.len() > 1 {
+            self.views.pop();
+        }
+        self.push_view(view);
+    }
+
+    /// Hands the event to the message log or the top view and follows the action it returns.
+    /// Returns true when the screen changed and has to be drawn again.
+    pub fn handle_event(&mut self, event: AppEvent) -> bool{
+        let action = match event {
+            //The message log takes its key in every view and all keys while it is open
+            AppEvent::Key(key) if self.notifications.handle_key(key, &self.settings.borrow().keymap) => ViewAction::Nothing,
+            AppEvent::Key(key) => self.current_view().handle_inputs(key),
+            AppEvent::Mouse(mouse) => self.current_view().handle_mouse(mouse),
+            AppEvent::Resize => ViewAction::Nothing,
+            AppEvent::Tick(now) => self.update_views(now),
+            AppEvent::Background => self.update_views(Instant::now()),
+        };
+        let mut changed = false;
+        for notification in self.views.iter_mut().flat_map(|view| view.take_notifications()) {
+            self.notifications.push(notification);
+            changed = true;
+        }
+        let live = self.views[self.first_visible()..].iter().any(|view| view.is_live());
+        changed |= !matches!(event, AppEvent::Tick(_)) || !matches!(action, ViewAction::Nothing) || live;
+        if let AppEvent::Tick(now) = event {
+            changed |= self.notifications.toasts_changed(now);
+        }
+        self.handle_view_action(action);
+        crash_report::record_state(self.current_view().name(), self.current_view().describe_state());
+        changed
+    }
+
+    /// Draws the whole screen, the views on the theme background and the notifications above them.
+    pub fn draw(&mut self, frame: &mut Frame, now: Instant){
+        //Theme background and text color under every view
+        let base_style = self.settings.borrow().theme.base_style();
+        frame.render_widget(Block::new().style(base_style), frame.area());
+        self.render(frame);
+        let settings = self.settings.borrow();
+        self.notifications.render(frame, &settings.theme, &settings.keymap, now);
+    }
+
+    /// Updates the top view, the views it covers only do their background work. Returns the action of the top view.
+    fn update_views(&mut self, now: Instant) -> ViewAction{
+        let top = self.views.len() - 1;
+        for view in &mut self.views[..top] {
+            view.background_update(now);
+        }
+        self.current_view().update(now)
+    }
+
+    /// Index of the first view drawn, the top view and the overlays below it are drawn over it.
+    fn first_visible(&self) -> usize{
+        self.views.iter().rposition(|view| !view.is_overlay()).unwrap_or(0)
+    }
+
+    /// Draws the top view and, when it is an overlay, the views below it up to the first full screen one.
+    pub fn render(&mut self, frame: &mut Frame){
+        let first_visible = self.first_visible();
+        for view in &mut self.views[first_visible..] {
+            view.render_widgets(frame);
+        }
     }
 }
 
 
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let mut state = AppState::new();
+/// Leaves raw mode, the alternate screen and mouse capture, safe to call more than once.
+fn restore_terminal(){
+    let _ = execute!(std::io::stdout(), DisableMouseCapture, cursor::Show);
+    ratatui::restore();
+}
 
-    let mut terminal = ratatui::init();
+//Runs before the default hook prints the panic, so the message ends up on a normal terminal
+fn install_panic_hook(data_dir: PathBuf){
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        restore_terminal();
+        previous_hook(info);
+        match crash_report::write(&data_dir, info) {
+            Ok(path) => eprintln!("Crash report saved to {}", path.display()),
+            Err(error) => eprintln!("Could not save crash report: {}", error),
+        }
+    }));
+}
 
+fn run(terminal: &mut DefaultTerminal, state: &mut AppState) -> Result<(), Box<dyn std::error::Error>> {
+    let mut events = EventLoop::new(TICK_RATE);
+    let mut needs_redraw = true;
     while state.running {
-        terminal.draw(|frame| {
-            let action = state.current_view.render(frame);
-            state.handle_view_action(action);
-        }).expect("Drawing terminal failed");
-        thread::sleep(Duration::from_millis(17));
+        //Input and updates change the state, drawing happens at most once per tick and only after a change.
+        //Messages of the opponent are drawn right away
+        let event = events.next()?;
+        needs_redraw |= state.handle_event(event);
+
+        if needs_redraw && matches!(event, AppEvent::Tick(_) | AppEvent::Resize | AppEvent::Background) {
+            terminal.draw(|frame| state.draw(frame, Instant::now()))?;
+            needs_redraw = false;
+        }
     }
     Ok(())
+}
+
+fn main() {
+    let mut settings = Settings::new();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Err(message) = cli::apply_args(&args, &mut settings){
+        eprintln!("{}", message);
+        std::process::exit(2);
+    }
+
+    let data_dir = settings.data_dir.clone();
+    let mut state = AppState::new(settings);
+
+    let mut terminal = ratatui::init();
+    install_panic_hook(data_dir);
+    let result = execute!(std::io::stdout(), EnableMouseCapture)
+        .map_err(|error| error.into())
+        .and_then(|_| run(&mut terminal, &mut state));
+    restore_terminal();
+
+    if let Err(error) = result {
+        eprintln!("TicTacToe stopped because of an error: {}", error);
+        std::process::exit(1);
+    }
 }
\ No newline at end of file
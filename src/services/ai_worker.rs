@@ -0,0 +1,76 @@
+use std::sync::mpsc;
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::enums::ai_level::AiLevel;
+use crate::enums::player::Player;
+use crate::services::event_loop;
+use crate::services::game::Game;
+
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(20);
+
+enum AiMessage{
+    Progress(u16),
+    Move(u8),
+}
+
+/// Searches the AI move on a background thread, so the interface keeps drawing and reading keys meanwhile.
+/// Dropping the worker abandons the search, the thread stops once it notices nobody listens anymore.
+pub struct AiWorker{
+    receiver: Receiver<AiMessage>,
+    progress: u16,
+}
+
+impl AiWorker{
+    /// Starts the search on a copy of the game, the move is not handed out before `think_time` passed.
+    pub fn start(game: &Game, player: Player, level: AiLevel, think_time: Duration) -> AiWorker{
+        let game = game.clone();
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move ||{
+            let started = Instant::now();
+            //Shown progress is the smaller one of the search and the think time, so neither jumps ahead
+            let time_progress = || {
+                let total = think_time.as_millis().max(1);
+                (started.elapsed().as_millis() * 100 / total).min(100) as u16
+            };
+
+            let ai_move = game.get_ai_move_for_level(player, level, &mut |search_progress| {
+                let _ = sender.send(AiMessage::Progress(search_progress.min(time_progress())));
+            });
+
+            while started.elapsed() < think_time {
+                if sender.send(AiMessage::Progress(time_progress())).is_err() {
+                    return;
+                }
+                thread::sleep(PROGRESS_INTERVAL.min(think_time.saturating_sub(started.elapsed())));
+            }
+            if let Some(index) = ai_move {
+                let _ = sender.send(AiMessage::Progress(100));
+                let _ = sender.send(AiMessage::Move(index));
+            }
+            //Progress is only drawn while the view is live, the move or the failed search wakes the loop
+            drop(sender);
+            event_loop::wake();
+        });
+
+        AiWorker{ receiver, progress: 0 }
+    }
+
+    /// Progress in percent as of the last poll.
+    pub fn progress(&self) -> u16{
+        self.progress
+    }
+
+    /// Returns the move once the search finished, without blocking.
+    pub fn poll_move(&mut self) -> Result<Option<u8>, String>{
+        loop {
+            match self.receiver.try_recv() {
+                Ok(AiMessage::Progress(progress)) => self.progress = progress,
+                Ok(AiMessage::Move(index)) => return Ok(Some(index)),
+                Err(TryRecvError::Empty) => return Ok(None),
+                Err(TryRecvError::Disconnected) => return Err("AI could not find a move".to_string()),
+            }
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use ratatui::text::Line;
+use crate::enums::art_entry::ArtEntry;
+use crate::services::locale::{tr, tr_args};
+use crate::services::storage;
+
+pub const DEFAULT_PACK: &str = "Default";
+const ART_DIR: &str = "art";
+const SELECTION_FILE: &str = "art_pack.tsv";
+
+/// Art read from `<data dir>/art/<pack name>/<entry>.txt`, entries without a valid file keep the built in art.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArtPack{
+    pub name: String,
+    entries: Vec<(ArtEntry, Vec<String>)>,
+    /// Files that were skipped and why, shown when the pack is selected.
+    pub problems: Vec<String>,
+}
+
+impl ArtPack{
+    pub fn built_in() -> ArtPack{
+        ArtPack{ name: DEFAULT_PACK.to_string(), entries: Vec::new(), problems: Vec::new() }
+    }
+
+    pub fn get(&self, entry: ArtEntry) -> Option<&[String]>{
+        self.entries.iter().find(|(saved, _)| *saved == entry).map(|(_, lines)| lines.as_slice())
+    }
+
+    /// Default pack first, then every pack directory in alphabetical order.
+    pub fn available(data_dir: &Path) -> Vec<String>{
+        let mut names: Vec<String> = fs::read_dir(art_dir(data_dir))
+            .map(|entries| entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .collect())
+            .unwrap_or_default();
+        names.sort();
+        names.retain(|name| name != DEFAULT_PACK);
+        names.insert(0, DEFAULT_PACK.to_string());
+        names
+    }
+
+    pub fn load(data_dir: &Path, name: &str) -> io::Result<ArtPack>{
+        let mut pack = ArtPack{ name: name.to_string(), ..ArtPack::built_in() };
+        if name == DEFAULT_PACK {
+            return Ok(pack);
+        }
+
+        let mut paths: Vec<PathBuf> = fs::read_dir(art_dir(data_dir).join(name))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file())
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let file_name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            let entry = file_name.strip_suffix(".txt").and_then(ArtEntry::from_name);
+            let Some(entry) = entry else {
+                pack.problems.push(format!("{}: {}", file_name, tr("unknown art file")));
+                continue;
+            };
+            match fs::read_to_string(&path).map_err(|error| error.to_string()).and_then(|content| validate(entry, &content)) {
+                Ok(lines) => pack.entries.push((entry, lines)),
+                Err(problem) => pack.problems.push(format!("{}: {}", file_name, problem)),
+            }
+        }
+        Ok(pack)
+    }
+
+    /// Pack chosen in settings, falls back to the default pack when it cannot be read anymore.
+    pub fn load_selected(data_dir: &Path) -> ArtPack{
+        let rows = storage::read_rows(&data_dir.join(SELECTION_FILE)).unwrap_or_default();
+        let name = rows.first().and_then(|row| row.get(1)).cloned().unwrap_or(DEFAULT_PACK.to_string());
+        ArtPack::load(data_dir, &name).unwrap_or(ArtPack::built_in())
+    }
+
+    pub fn save_selection(&self, data_dir: &Path) -> io::Result<()>{
+        storage::write_rows(&data_dir.join(SELECTION_FILE), &[vec!["pack".to_string(), storage::sanitize(&self.name)]])
+    }
+}
+
+fn art_dir(data_dir: &Path) -> PathBuf{
+    data_dir.join(ART_DIR)
+}
+
+/// Checks that the art fits the entry, leading and trailing empty lines are dropped.
+fn validate(entry: ArtEntry, content: &str) -> Result<Vec<String>, String>{
+    let lines: Vec<&str> = content.lines().map(|line| line.trim_end_matches('\r')).collect();
+    let first = lines.iter().position(|line| !line.trim().is_empty());
+    let last = lines.iter().rposition(|line| !line.trim().is_empty());
+    let (Some(first), Some(last)) = (first, last) else {
+        return Err(tr("file is empty").to_string());
+    };
+    let lines = &lines[first..=last];
+
+    if lines.iter().any(|line| line.chars().any(char::is_control)) {
+        return Err(tr("tabs and control characters are not allowed").to_string());
+    }
+    let (max_height, max_width) = entry.max_size();
+    let width = lines.iter().map(|line| Line::from(*line).width()).max().unwrap_or(0);
+    if lines.len() > max_height || width > max_width {
+        return Err(tr_args("art is {}x{}, at most {}x{} fits", &[&lines.len(), &width, &max_height, &max_width]));
+    }
+    Ok(lines.iter().map(|line| line.to_string()).collect())
+}
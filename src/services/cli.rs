@@ -0,0 +1,53 @@
+use std::time::Duration;
+use crate::services::clock::TimeControl;
+use crate::services::engine::EngineConfig;
+use crate::services::settings::Settings;
+
+pub const USAGE: &str = "Usage: TicTacToe [--engine \"<command> [args]\"] [--engine-timeout <ms>] [--port <port>] [--discovery-port <port>] [--data-dir <path>] [--time-control <total>[+<increment>][/<per move>]]";
+
+/// Applies command line arguments (without the program name) on top of the settings.
+pub fn apply_args(args: &[String], settings: &mut Settings) -> Result<(), String>{
+    let mut engine_timeout: Option<Duration> = None;
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--engine" => {
+                let command = iter.next().ok_or("--engine requires a command")?;
+                let config = EngineConfig::new(command).ok_or("--engine command is empty")?;
+                settings.engine = Some(config);
+            }
+            "--engine-timeout" => {
+                let value = iter.next().ok_or("--engine-timeout requires a value in milliseconds")?;
+                let millis: u64 = value.parse().map_err(|_| format!("Invalid --engine-timeout value: {}", value))?;
+                engine_timeout = Some(Duration::from_millis(millis));
+            }
+            "--port" => {
+                let value = iter.next().ok_or("--port requires a port number")?;
+                settings.network_port = value.parse().map_err(|_| format!("Invalid --port value: {}", value))?;
+            }
+            "--discovery-port" => {
+                let value = iter.next().ok_or("--discovery-port requires a port number")?;
+                settings.discovery_port = value.parse().map_err(|_| format!("Invalid --discovery-port value: {}", value))?;
+            }
+            "--data-dir" => {
+                let value = iter.next().ok_or("--data-dir requires a path")?;
+                settings.data_dir = value.into();
+            }
+            "--time-control" => {
+                let value = iter.next().ok_or("--time-control requires a value like 180+2 or 0/10 (seconds)")?;
+                settings.time_control = TimeControl::parse(value).ok_or(format!("Invalid --time-control value: {}", value))?;
+            }
+            "--help" | "-h" => return Err(USAGE.to_string()),
+            other => return Err(format!("Unknown argument: {}\n{}", other, USAGE)),
+        }
+    }
+
+    if let Some(timeout) = engine_timeout {
+        match settings.engine.as_mut() {
+            Some(engine) => engine.move_timeout = timeout,
+            None => return Err("--engine-timeout requires --engine".to_string()),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use std::time::{Duration, Instant};
+use crate::enums::field::FieldMark;
+use crate::services::locale::{tr, tr_args};
+
+/// Limits for one game, `None` means that limit is not used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeControl{
+    pub total: Option<Duration>,
+    pub per_move: Option<Duration>,
+    pub increment: Duration,
+}
+
+impl TimeControl{
+    pub const UNLIMITED: TimeControl = TimeControl{ total: None, per_move: None, increment: Duration::ZERO };
+
+    pub fn presets() -> [TimeControl; 5]{
+        [
+            TimeControl::UNLIMITED,
+            TimeControl{ total: Some(Duration::from_secs(60)), per_move: None, increment: Duration::ZERO },
+            TimeControl{ total: Some(Duration::from_secs(180)), per_move: None, increment: Duration::from_secs(2) },
+            TimeControl{ total: Some(Duration::from_secs(300)), per_move: None, increment: Duration::ZERO },
+            TimeControl{ total: None, per_move: Some(Duration::from_secs(10)), increment: Duration::ZERO },
+        ]
+    }
+
+    pub fn is_unlimited(&self) -> bool{
+        self.total.is_none() && self.per_move.is_none()
+    }
+
+    /// Parses `<total>[+<increment>][/<per move>]` in seconds, e.g. `180+2` or `0/10`, a total of 0 means no total limit.
+    pub fn parse(text: &str) -> Option<TimeControl>{
+        let seconds = |value: &str| value.trim().parse::<u64>().ok().map(Duration::from_secs);
+        let (limits, per_move) = match text.split_once('/') {
+            Some((limits, per_move)) => (limits, Some(seconds(per_move)?)),
+            None => (text, None),
+        };
+        let (total, increment) = match limits.split_once('+') {
+            Some((total, increment)) => (seconds(total)?, seconds(increment)?),
+            None => (seconds(limits)?, Duration::ZERO),
+        };
+
+        Some(TimeControl{
+            total: Some(total).filter(|total| !total.is_zero()),
+            per_move: per_move.filter(|per_move| !per_move.is_zero()),
+            increment,
+        })
+    }
+
+    pub fn label(&self) -> String{
+        if self.is_unlimited() {
+            return tr("Unlimited").to_string();
+        }
+        let mut parts = Vec::new();
+        if let Some(total) = self.total {
+            let total_text = if total.as_secs() % 60 == 0 {tr_args("{} min", &[&(total.as_secs() / 60)])} else {tr_args("{} s", &[&total.as_secs()])};
+            if self.increment.is_zero() {
+                parts.push(total_text);
+            }else{
+                parts.push(tr_args("{} + {} s", &[&total_text, &self.increment.as_secs()]));
+            }
+        }
+        if let Some(per_move) = self.per_move {
+            parts.push(tr_args("{} s per move", &[&per_move.as_secs()]));
+        }
+        parts.join(", ")
+    }
+
+    /// Next preset, a custom time control from the command line continues with the first preset.
+    pub fn next(&self) -> TimeControl{
+        let presets = Self::presets();
+        match presets.iter().position(|preset| preset == self) {
+            Some(index) => presets[(index + 1) % presets.len()],
+            None => presets[0],
+        }
+    }
+
+    pub fn previous(&self) -> TimeControl{
+        let presets = Self::presets();
+        match presets.iter().position(|preset| preset == self) {
+            Some(index) => presets[(index + presets.len() - 1) % presets.len()],
+            None => presets[presets.len() - 1],
+        }
+    }
+}
+
+/// Counts down the time of the side to move, cross always starts.
+pub struct GameClock{
+    control: TimeControl,
+    cross_remaining: Duration,
+    circle_remaining: Duration,
+    turn: FieldMark,
+    turn_started: Instant,
+    last_tick: Instant,
+    stopped: bool,
+    paused_at: Option<Instant>,
+}
+
+impl GameClock{
+    pub fn new(control: TimeControl, now: Instant) -> GameClock{
+        let total = control.total.unwrap_or(Duration::ZERO);
+        GameClock{
+            control,
+            cross_remaining: total,
+            circle_remaining: total,
+            turn: FieldMark::X,
+            turn_started: now,
+            last_tick: now,
+            stopped: false,
+            paused_at: None,
+        }
+    }
+
+    pub fn turn(&self) -> FieldMark{
+        self.turn
+    }
+
+    pub fn is_stopped(&self) -> bool{
+        self.stopped
+    }
+
+    /// Time left on the total clock of `mark`, None without a total limit.
+    pub fn remaining(&self, mark: FieldMark) -> Option<Duration>{
+        self.control.total?;
+        Some(if mark == FieldMark::O {self.circle_remaining} else {self.cross_remaining})
+    }
+
+    /// Time left for the current move, None without a per move limit.
+    pub fn move_remaining(&self, now: Instant) -> Option<Duration>{
+        let limit = self.control.per_move?;
+        let now = self.paused_at.unwrap_or(now);
+        Some(limit.saturating_sub(now.saturating_duration_since(self.turn_started)))
+    }
+
+    /// Charges the time since the last tick to the side to move, returns that side when it ran out of time.
+    pub fn tick(&mut self, now: Instant) -> Option<FieldMark>{
+        if self.stopped || self.paused_at.is_some() {
+            return None;
+        }
+        let elapsed = now.saturating_duration_since(self.last_tick);
+        self.last_tick = now;
+
+        let remaining = if self.turn == FieldMark::O {&mut self.circle_remaining} else {&mut self.cross_remaining};
+        *remaining = remaining.saturating_sub(elapsed);
+
+        let total_flagged = self.control.total.is_some() && remaining.is_zero();
+        let move_flagged = self.move_remaining(now).is_some_and(|left| left.is_zero());
+        if total_flagged || move_flagged {Some(self.turn)} else {None}
+    }
+
+    /// Ends the turn of the side that just moved, adds its increment and starts the clock of `to_move`.
+    /// While paused the new turn starts at the pause, `resume` moves it by the paused time.
+    pub fn switch_turn(&mut self, to_move: FieldMark, now: Instant){
+        if self.stopped || to_move == self.turn {
+            return;
+        }
+        let now = self.paused_at.unwrap_or(now);
+        self.tick(now);
+        if self.control.total.is_some() {
+            let remaining = if self.turn == FieldMark::O {&mut self.circle_remaining} else {&mut self.cross_remaining};
+            *remaining += self.control.increment;
+        }
+        self.turn = to_move;
+        self.turn_started = now;
+    }
+
+    pub fn stop(&mut self){
+        self.stopped = true;
+    }
+
+    /// Charges the time until now and freezes the clock until `resume`.
+    pub fn pause(&mut self, now: Instant){
+        if self.paused_at.is_none() {
+            self.tick(now);
+            self.paused_at = Some(now);
+        }
+    }
+
+    //The paused time counts for nobody, also not for the per move limit
+    pub fn resume(&mut self, now: Instant){
+        let Some(paused_at) = self.paused_at.take() else { return };
+        self.turn_started += now.saturating_duration_since(paused_at);
+        self.last_tick = now;
+    }
+}
+
+/// Formats clock time as `m:ss`, with tenths of a second during the last ten seconds.
+pub fn format_duration(duration: Duration) -> String{
+    if duration < Duration::from_secs(10) {
+        format!("0:{:02}.{}", duration.as_secs(), duration.subsec_millis() / 100)
+    }else{
+        format!("{}:{:02}", duration.as_secs() / 60, duration.as_secs() % 60)
+    }
+}
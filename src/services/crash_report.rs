@@ -0,0 +1,46 @@
+use std::fs;
+use std::io;
+use std::panic::PanicHookInfo;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// What the app was doing, kept up to date by the main loop so a panic hook can still read it.
+static LAST_STATE: Mutex<Option<(String, String)>> = Mutex::new(None);
+
+pub fn record_state(view: &str, state: String){
+    if let Ok(mut last_state) = LAST_STATE.lock() {
+        *last_state = Some((view.to_string(), state));
+    }
+}
+
+/// Writes `crash-<unix time>.txt` into the data directory and returns its path.
+pub fn write(data_dir: &Path, info: &PanicHookInfo) -> io::Result<PathBuf>{
+    let time = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_secs()).unwrap_or(0);
+    //A panic while the state was recorded poisons the lock, the state is still worth reporting
+    let (view, state) = match LAST_STATE.lock() {
+        Ok(last_state) => last_state.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    }.unwrap_or(("none".to_string(), String::new()));
+
+    let location = info.location().map(|location| location.to_string()).unwrap_or("unknown".to_string());
+    let report = format!(
+        "TicTacToe {} crash report\ntime: {}\npanic: {}\nlocation: {}\nview: {}\n\n{}\n",
+        env!("CARGO_PKG_VERSION"), time, panic_message(info), location, view, state,
+    );
+
+    fs::create_dir_all(data_dir)?;
+    let path = data_dir.join(format!("crash-{}.txt", time));
+    fs::write(&path, report)?;
+    Ok(path)
+}
+
+fn panic_message(info: &PanicHookInfo) -> String{
+    if let Some(message) = info.payload().downcast_ref::<&str>() {
+        message.to_string()
+    }else if let Some(message) = info.payload().downcast_ref::<String>() {
+        message.clone()
+    }else{
+        "unknown".to_string()
+    }
+}
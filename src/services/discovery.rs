@@ -0,0 +1,175 @@
+//! Finding hosted games on the local network.
+//!
+//! A lobby broadcasts `tictactoe-discover <version>` to [`DISCOVERY_PORT`] and every host
+//! listening there answers directly to the sender with
+//! `tictactoe-game <version> <tcp_port> <variant> <open|full> <host name>`.
+//! Queries are also sent to the loopback address so games hosted on the same machine are found.
+//! Games are told apart by the address of the answer and the TCP port, host names do not have to be unique.
+
+use std::io::ErrorKind;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket};
+use std::time::{Duration, Instant};
+use crate::services::network::NetworkError;
+
+pub const DISCOVERY_PORT: u16 = 7879;
+pub const VARIANT: &str = "classic";
+const DISCOVERY_VERSION: u32 = 1;
+const QUERY_INTERVAL: Duration = Duration::from_secs(1);
+const GAME_EXPIRY: Duration = Duration::from_secs(4);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveredGame{
+    pub address: SocketAddr,
+    pub host_name: String,
+    pub variant: String,
+    pub open: bool,
+    /// Hosted on this machine, it answers from the loopback and from the network address.
+    local: bool,
+    last_seen: Instant,
+}
+
+fn query_message() -> String{
+    format!("tictactoe-discover {}", DISCOVERY_VERSION)
+}
+
+fn answer_message(tcp_port: u16, open: bool, host_name: &str) -> String{
+    let status = if open {"open"} else {"full"};
+    format!("tictactoe-game {} {} {} {} {}", DISCOVERY_VERSION, tcp_port, VARIANT, status, host_name)
+}
+
+fn parse_answer(text: &str, sender: SocketAddr) -> Option<DiscoveredGame>{
+    let mut parts = text.trim().splitn(6, ' ');
+    if parts.next()? != "tictactoe-game" || parts.next()?.parse::<u32>().ok()? != DISCOVERY_VERSION {
+        return None;
+    }
+    let tcp_port: u16 = parts.next()?.parse().ok()?;
+    let variant = parts.next()?.to_string();
+    let open = parts.next()? == "open";
+    let host_name = parts.next().unwrap_or("unknown").to_string();
+
+    Some(DiscoveredGame{
+        address: SocketAddr::new(sender.ip(), tcp_port),
+        host_name,
+        variant,
+        open,
+        local: is_local_address(sender.ip()),
+        last_seen: Instant::now(),
+    })
+}
+
+//Only addresses of this machine can be bound
+fn is_local_address(address: IpAddr) -> bool{
+    address.is_loopback() || UdpSocket::bind((address, 0)).is_ok()
+}
+
+/// Name shown to other players in the lobby.
+pub fn local_host_name() -> String{
+    std::env::var("HOSTNAME").ok()
+        .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or("unknown".to_string())
+}
+
+/// Host side, answers lobby queries for one hosted game.
+pub struct DiscoveryResponder{
+    socket: UdpSocket,
+    tcp_port: u16,
+    host_name: String,
+}
+
+impl DiscoveryResponder{
+    pub fn bind(discovery_port: u16, tcp_port: u16, host_name: String) -> Result<DiscoveryResponder, NetworkError>{
+        let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, discovery_port))?;
+        socket.set_nonblocking(true)?;
+        Ok(DiscoveryResponder{ socket, tcp_port, host_name })
+    }
+
+    pub fn answer_queries(&self, open: bool){
+        let mut buffer = [0u8; 512];
+        while let Ok((length, sender)) = self.socket.recv_from(&mut buffer) {
+            if String::from_utf8_lossy(&buffer[..length]).trim() == query_message() {
+                let answer = answer_message(self.tcp_port, open, &self.host_name);
+                let _ = self.socket.send_to(answer.as_bytes(), sender);
+            }
+        }
+    }
+}
+
+/// Lobby side, periodically asks for games and collects the answers.
+pub struct DiscoveryClient{
+    socket: UdpSocket,
+    discovery_port: u16,
+    last_query: Option<Instant>,
+    games: Vec<DiscoveredGame>,
+}
+
+impl DiscoveryClient{
+    pub fn new(discovery_port: u16) -> Result<DiscoveryClient, NetworkError>{
+        let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))?;
+        socket.set_broadcast(true)?;
+        socket.set_nonblocking(true)?;
+        Ok(DiscoveryClient{ socket, discovery_port, last_query: None, games: Vec::new() })
+    }
+
+    pub fn games(&self) -> &[DiscoveredGame]{
+        &self.games
+    }
+
+    /// Sends a query when due and reads all answers that arrived, never blocks.
+    pub fn update(&mut self) -> Result<(), NetworkError>{
+        if self.last_query.is_none_or(|last| last.elapsed() >= QUERY_INTERVAL) {
+            self.send_query()?;
+            self.last_query = Some(Instant::now());
+        }
+
+        let mut buffer = [0u8; 512];
+        loop {
+            match self.socket.recv_from(&mut buffer) {
+                Ok((length, sender)) => {
+                    if let Some(game) = parse_answer(&String::from_utf8_lossy(&buffer[..length]), sender) {
+                        self.add_game(game);
+                    }
+                }
+                Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+                //Unreachable ports on loopback are reported back as errors on some systems
+                Err(error) if error.kind() == ErrorKind::ConnectionRefused => continue,
+                Err(error) => return Err(error.into()),
+            }
+        }
+
+        self.games.retain(|game| game.last_seen.elapsed() < GAME_EXPIRY);
+        Ok(())
+    }
+
+    fn send_query(&self) -> Result<(), NetworkError>{
+        let query = query_message();
+        let loopback = SocketAddr::from((Ipv4Addr::LOCALHOST, self.discovery_port));
+        self.socket.send_to(query.as_bytes(), loopback)?;
+
+        //Broadcast is not available everywhere (e.g. without a network interface), loopback still works then
+        let broadcast = SocketAddr::from((Ipv4Addr::BROADCAST, self.discovery_port));
+        let _ = self.socket.send_to(query.as_bytes(), broadcast);
+        Ok(())
+    }
+
+    fn add_game(&mut self, game: DiscoveredGame){
+        //A game on this machine answers the loopback query and the broadcast one, each from another address
+        let duplicate = self.games.iter_mut().find(|known| {
+            known.address.port() == game.address.port()
+                && (known.address.ip() == game.address.ip() || (known.local && game.local))
+        });
+        match duplicate {
+            Some(known) => {
+                //The loopback address keeps working when the network changes
+                if game.address.ip().is_loopback() {
+                    known.address = game.address;
+                }
+                known.host_name = game.host_name;
+                known.open = game.open;
+                known.last_seen = game.last_seen;
+            }
+            None => self.games.push(game),
+        }
+    }
+}
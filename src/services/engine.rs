@@ -0,0 +1,286 @@
+//! Line based protocol for external engines (bots written in any language).
+//!
+//! The game talks to the engine over its stdin/stdout, one command per line:
+//!
+//! ```text
+//! gui -> engine               engine -> gui
+//! tictactoe                   id name <name>      (optional)
+//!                             tictactoeok
+//! isready                     readyok
+//! newgame
+//! position <board> <side>
+//! go                          bestmove <0-8>
+//! quit
+//! ```
+//!
+//! `<board>` is nine characters in row-major order using `x`, `o` and `.` for empty fields,
+//! `<side>` is the mark (`x` or `o`) the engine plays with. Lines the game does not expect
+//! (for example `info ...`) are ignored.
+
+use std::fmt;
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc;
+use std::sync::mpsc::{Receiver, RecvTimeoutError, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::enums::field::FieldMark;
+use crate::services::event_loop;
+use crate::services::game::{encode_board, Game};
+use crate::services::locale::{tr, tr_args};
+
+const DEFAULT_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(3);
+const DEFAULT_MOVE_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone)]
+pub struct EngineConfig{
+    pub command: String,
+    pub args: Vec<String>,
+    pub handshake_timeout: Duration,
+    pub move_timeout: Duration,
+}
+
+impl EngineConfig{
+    pub fn new(command_line: &str) -> Option<EngineConfig>{
+        let mut parts = command_line.split_whitespace().map(String::from);
+        let command = parts.next()?;
+
+        Some(EngineConfig{
+            command,
+            args: parts.collect(),
+            handshake_timeout: DEFAULT_HANDSHAKE_TIMEOUT,
+            move_timeout: DEFAULT_MOVE_TIMEOUT,
+        })
+    }
+
+    /// Command and arguments as typed, the form `new` reads and the settings file stores.
+    pub fn command_line(&self) -> String{
+        std::iter::once(&self.command).chain(&self.args).cloned().collect::<Vec<String>>().join(" ")
+    }
+}
+
+#[derive(Debug)]
+pub enum EngineError{
+    NotConfigured,
+    Spawn(String),
+    Io(String),
+    Timeout(&'static str),
+    Exited,
+    Protocol(String),
+    IllegalMove(u8),
+}
+
+impl fmt::Display for EngineError{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result{
+        match self {
+            EngineError::NotConfigured => write!(f, "{}", tr("No engine configured, enter its command in Settings or start the game with --engine <command>")),
+            EngineError::Spawn(message) => write!(f, "{}", tr_args("Could not start engine: {}", &[message])),
+            EngineError::Io(message) => write!(f, "{}", tr_args("Engine communication failed: {}", &[message])),
+            EngineError::Timeout(what) => write!(f, "{}", tr_args("Engine did not answer in time ({})", &[what])),
+            EngineError::Exited => write!(f, "{}", tr("Engine process exited")),
+            EngineError::Protocol(line) => write!(f, "{}", tr_args("Engine sent unexpected line: {}", &[line])),
+            EngineError::IllegalMove(index) => write!(f, "{}", tr_args("Engine played illegal move {}", &[index])),
+        }
+    }
+}
+
+impl std::error::Error for EngineError {}
+
+pub struct Engine{
+    name: String,
+    child: Child,
+    stdin: ChildStdin,
+    lines: Receiver<String>,
+    move_timeout: Duration,
+    move_requested_at: Option<Instant>,
+}
+
+impl Engine{
+    pub fn spawn(config: &EngineConfig) -> Result<Engine, EngineError>{
+        let mut child = Command::new(&config.command)
+            .args(&config.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|error| EngineError::Spawn(format!("{} ({})", config.command, error)))?;
+
+        let stdin = child.stdin.take().ok_or(EngineError::Spawn("missing stdin".to_string()))?;
+        let stdout = child.stdout.take().ok_or(EngineError::Spawn("missing stdout".to_string()))?;
+
+        //Reading on a separate thread lets us wait for answers with a timeout
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move ||{
+            for line in BufReader::new(stdout).lines() {
+                let Ok(line) = line else { break };
+                if sender.send(line).is_err() {
+                    break;
+                }
+                event_loop::wake();
+            }
+            drop(sender);
+            event_loop::wake();
+        });
+
+        let mut engine = Engine{
+            name: config.command.clone(),
+            child,
+            stdin,
+            lines: receiver,
+            move_timeout: config.move_timeout,
+            move_requested_at: None,
+        };
+        engine.handshake(config.handshake_timeout)?;
+        Ok(engine)
+    }
+
+    pub fn name(&self) -> &str{
+        &self.name
+    }
+
+    pub fn is_thinking(&self) -> bool{
+        self.move_requested_at.is_some()
+    }
+
+    /// Fraction of the move timeout already used, in percent.
+    pub fn thinking_progress(&self) -> u16{
+        match self.move_requested_at {
+            None => 0,
+            Some(start) => {
+                let elapsed = start.elapsed().as_millis();
+                let total = self.move_timeout.as_millis().max(1);
+                (elapsed * 100 / total).min(100) as u16
+            }
+        }
+    }
+
+    fn handshake(&mut self, timeout: Duration) -> Result<(), EngineError>{
+        self.send("tictactoe")?;
+        let deadline = Instant::now() + timeout;
+        loop {
+            let line = self.receive_until(deadline, "handshake")?;
+            if let Some(name) = line.strip_prefix("id name ") {
+                self.name = name.trim().to_string();
+            }
+            else if line == "tictactoeok" {
+                break;
+            }
+        }
+        self.send("isready")?;
+        self.expect("readyok", deadline, "isready")
+    }
+
+    pub fn new_game(&mut self) -> Result<(), EngineError>{
+        self.move_requested_at = None;
+        self.send("newgame")?;
+        self.send("isready")?;
+        self.expect("readyok", Instant::now() + self.move_timeout, "isready")
+    }
+
+    /// Sends the position and starts the search, the answer is picked up by [`Engine::poll_move`].
+    pub fn request_move(&mut self, game: &Game) -> Result<(), EngineError>{
+        let side = game.current_mark().as_char();
+        self.send(&format!("position {} {}", encode_board(&game.board), side))?;
+        self.send("go")?;
+        self.move_requested_at = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Returns the engine move once it arrives, without blocking.
+    pub fn poll_move(&mut self, game: &Game) -> Result<Option<u8>, EngineError>{
+        let Some(requested_at) = self.move_requested_at else { return Ok(None) };
+
+        loop {
+            match self.lines.try_recv() {
+                Ok(line) => {
+                    if let Some(index) = line.strip_prefix("bestmove ") {
+                        self.move_requested_at = None;
+                        return Self::parse_move(index.trim(), game).map(Some);
+                    }
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => return Err(EngineError::Exited),
+            }
+        }
+
+        if requested_at.elapsed() > self.move_timeout {
+            self.move_requested_at = None;
+            return Err(EngineError::Timeout("go"));
+        }
+        Ok(None)
+    }
+
+    fn parse_move(text: &str, game: &Game) -> Result<u8, EngineError>{
+        let index: u8 = text.parse().map_err(|_| EngineError::Protocol(format!("bestmove {}", text)))?;
+        if index >= 9 || game.board[index as usize] != FieldMark::Empty {
+            return Err(EngineError::IllegalMove(index));
+        }
+        Ok(index)
+    }
+
+    fn send(&mut self, line: &str) -> Result<(), EngineError>{
+        writeln!(self.stdin, "{}", line)
+            .and_then(|_| self.stdin.flush())
+            .map_err(|error| EngineError::Io(error.to_string()))
+    }
+
+    fn expect(&mut self, expected: &str, deadline: Instant, what: &'static str) -> Result<(), EngineError>{
+        loop {
+            if self.receive_until(deadline, what)? == expected {
+                return Ok(());
+            }
+        }
+    }
+
+    fn receive_until(&mut self, deadline: Instant, what: &'static str) -> Result<String, EngineError>{
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        match self.lines.recv_timeout(remaining) {
+            Ok(line) => Ok(line.trim().to_string()),
+            Err(RecvTimeoutError::Timeout) => Err(EngineError::Timeout(what)),
+            Err(RecvTimeoutError::Disconnected) => Err(EngineError::Exited),
+        }
+    }
+}
+
+/// Starts the engine and waits for its handshake on a background thread, so the interface keeps drawing
+/// while a slow engine starts. Dropping the launcher stops the engine once it is up.
+pub struct EngineLauncher{
+    receiver: Receiver<Result<Engine, EngineError>>,
+}
+
+impl EngineLauncher{
+    pub fn start(config: EngineConfig) -> EngineLauncher{
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move ||{
+            let engine = Engine::spawn(&config).and_then(|mut engine| engine.new_game().map(|_| engine));
+            //Nobody listens when the game was left meanwhile, the engine is dropped and told to quit
+            let _ = sender.send(engine);
+            event_loop::wake();
+        });
+        EngineLauncher{ receiver }
+    }
+
+    /// The engine ready for a new game or the reason it did not start, None while it is still starting.
+    pub fn poll(&self) -> Option<Result<Engine, EngineError>>{
+        match self.receiver.try_recv() {
+            Ok(result) => Some(result),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(Err(EngineError::Exited)),
+        }
+    }
+}
+
+impl Drop for Engine{
+    fn drop(&mut self){
+        let _ = self.send("quit");
+        let deadline = Instant::now() + Duration::from_millis(200);
+        while Instant::now() < deadline {
+            if let Ok(Some(_)) = self.child.try_wait() {
+                return;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
@@ -0,0 +1,68 @@
+use std::io;
+use std::sync::Mutex;
+use std::sync::mpsc;
+use std::sync::mpsc::{Receiver, Sender};
+use std::time::{Duration, Instant};
+use ratatui::crossterm::event;
+use ratatui::crossterm::event::{Event, KeyEventKind};
+use crate::enums::app_event::AppEvent;
+
+/// How often the terminal wait is interrupted to look for messages of background threads.
+const WAKE_CHECK_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Set by the running event loop, nobody listens to [`wake`] before that and in tests.
+static WAKER: Mutex<Option<Sender<()>>> = Mutex::new(None);
+
+/// Tells the event loop that a background thread (AI, engine, network) queued a message for a view.
+/// Called after the message was sent, so the view finds it when it handles the [`AppEvent::Background`].
+pub fn wake(){
+    if let Ok(waker) = WAKER.lock()
+        && let Some(sender) = waker.as_ref() {
+        let _ = sender.send(());
+    }
+}
+
+/// Turns terminal input into [`AppEvent`]s and adds a tick at a fixed rate in between.
+pub struct EventLoop{
+    tick_rate: Duration,
+    next_tick: Instant,
+    wakes: Receiver<()>,
+}
+
+impl EventLoop{
+    pub fn new(tick_rate: Duration) -> EventLoop{
+        let (sender, wakes) = mpsc::channel();
+        if let Ok(mut waker) = WAKER.lock() {
+            *waker = Some(sender);
+        }
+        EventLoop{ tick_rate, next_tick: Instant::now(), wakes }
+    }
+
+    /// Waits for the next input event, background message or tick, whichever comes first.
+    pub fn next(&mut self) -> io::Result<AppEvent>{
+        loop {
+            let now = Instant::now();
+            if now >= self.next_tick {
+                //After a slow frame the ticks continue from now instead of catching up one by one
+                self.next_tick = (self.next_tick + self.tick_rate).max(now);
+                return Ok(AppEvent::Tick(now));
+            }
+
+            //Several messages that arrived together are handled in one go
+            if self.wakes.try_recv().is_ok() {
+                while self.wakes.try_recv().is_ok() {}
+                return Ok(AppEvent::Background);
+            }
+
+            if !event::poll((self.next_tick - now).min(WAKE_CHECK_INTERVAL))? {
+                continue;
+            }
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => return Ok(AppEvent::Key(key)),
+                Event::Mouse(mouse) => return Ok(AppEvent::Mouse(mouse)),
+                Event::Resize(_, _) => return Ok(AppEvent::Resize),
+                _ => {}
+            }
+        }
+    }
+}
@@ -1,26 +1,48 @@
+use crate::enums::ai_level::AiLevel;
 use crate::enums::field::FieldMark;
 use crate::enums::player::Player;
+use crate::enums::variant::Variant;
 use crate::helpers::vector_helper::VecExt;
 
+#[derive(Clone)]
 pub struct Game{
     pub board: [FieldMark;9],
     pub current_player: Player,
     pub player_mark: FieldMark,
     pub opponent_mark: FieldMark,
     pub winner: Option<FieldMark>,
+    pub moves: Vec<u8>,
+    /// Side that lost because its clock ran out.
+    pub timed_out: Option<FieldMark>,
+    pub variant: Variant,
 }
 
 impl Game{
     pub fn new() -> Game{
+        Game::with_marks(FieldMark::X, FieldMark::O)
+    }
+
+    /// Game where the local player uses `player_mark`, cross always moves first.
+    pub fn with_marks(player_mark: FieldMark, opponent_mark: FieldMark) -> Game{
         Game{
             board: [FieldMark::Empty; 9],
-            current_player: Player::Player,
-            player_mark: FieldMark::X,
-            opponent_mark: FieldMark::O,
-            winner: None
+            current_player: if player_mark == FieldMark::X {Player::Player} else {Player::Opponent},
+            player_mark,
+            opponent_mark,
+            winner: None,
+            moves: Vec::new(),
+            timed_out: None,
+            variant: Variant::Classic,
         }
     }
 
+    /// Overwrites the game with a state computed elsewhere, e.g. by the host of a network game.
+    pub fn apply_state(&mut self, board: [FieldMark; 9], to_move: FieldMark, winner: Option<FieldMark>){
+        self.board = board;
+        self.current_player = if to_move == self.player_mark {Player::Player} else {Player::Opponent};
+        self.winner = winner;
+    }
+
     pub fn make_move(&mut self, index: u8) -> Result<(),String>{
         if index >= 9 {
             return Err("Field number out of scope".to_string());
@@ -30,6 +52,7 @@ impl Game{
             return Err("Select empty field".to_string())
         }
 
+        self.moves.push(index);
         match self.current_player{
             Player::Player => {
                 self.board[index as usize] = self.player_mark;
@@ -45,6 +68,28 @@ impl Game{
         Ok(())
     }
 
+    /// Ends the game in favour of the other side because `mark` ran out of time.
+    pub fn time_out(&mut self, mark: FieldMark){
+        if self.winner.is_some() {
+            return;
+        }
+        self.timed_out = Some(mark);
+        self.winner = Some(if mark == self.player_mark {self.opponent_mark} else {self.player_mark});
+    }
+
+    pub fn current_mark(&self) -> FieldMark{
+        if self.current_player == Player::Player {self.player_mark} else {self.opponent_mark}
+    }
+
+    /// Tiles of the row, column or diagonal that won the game.
+    pub fn winning_line(&self) -> Option<[u8; 3]>{
+        const LINES: [[u8; 3]; 8] = [[0,1,2], [3,4,5], [6,7,8], [0,3,6], [1,4,7], [2,5,8], [0,4,8], [2,4,6]];
+        LINES.into_iter().find(|line| {
+            let mark = self.board[line[0] as usize];
+            mark != FieldMark::Empty && line.iter().all(|index| self.board[*index as usize] == mark)
+        })
+    }
+
     fn check_if_game_already_won(&mut self){
         let potential_winner: Option<FieldMark> = self.check_win(&self.board);
         if let Some(winner) = potential_winner{
@@ -62,43 +107,149 @@ impl Game{
             let checking_mark = if i == 0 {my_mark} else {enemy_mark};
 
             for (j, mark) in self.board.iter().enumerate(){
-                if(matches!(mark,FieldMark::Empty)){
+                if matches!(mark,FieldMark::Empty) {
 
-                    if(i == 0){
+                    if i == 0 {
                         empty_spaces.push(j as u8);
                     }
 
-                    let mut board_copy = self.board.clone();
-                    board_copy[j] = checking_mark.clone();
+                    let mut board_copy = self.board;
+                    board_copy[j] = checking_mark;
                     if let Some(winning_mark) = self.check_win(&board_copy) && winning_mark == checking_mark{
                         return Some(j as u8);
                     }
                 }
             }
         }
-        empty_spaces.random()
+        //In misère completing a line of its own loses, such fields are the last resort
+        let safe_spaces: Vec<u8> = empty_spaces.iter().copied()
+            .filter(|index| {
+                let mut board_copy = self.board;
+                board_copy[*index as usize] = my_mark;
+                self.check_win(&board_copy) != Some(enemy_mark)
+            })
+            .collect();
+        safe_spaces.random().or_else(|| empty_spaces.random())
+    }
+
+    /// Move of the AI, `on_progress` is called with the finished part of the search in percent.
+    pub fn get_ai_move_for_level(&self, player: Player, level: AiLevel, on_progress: &mut dyn FnMut(u16)) -> Option<u8>{
+        let ai_move = match level {
+            AiLevel::Easy => Self::get_empty_fields(&self.board).random(),
+            AiLevel::Normal => self.get_ai_move(player),
+            AiLevel::Hard => self.get_best_move(player, on_progress),
+        };
+        on_progress(100);
+        ai_move
+    }
+
+    fn get_empty_fields(board: &[FieldMark; 9]) -> Vec<u8>{
+        board.iter().enumerate()
+            .filter(|(_, mark)| **mark == FieldMark::Empty)
+            .map(|(index, _)| index as u8)
+            .collect()
+    }
+
+    /// Empty fields on `board` where `mark` would complete a line.
+    pub fn winning_moves(&self, board: &[FieldMark; 9], mark: FieldMark) -> Vec<u8>{
+        Self::get_empty_fields(board).into_iter()
+            .filter(|index| {
+                let mut board_copy = *board;
+                board_copy[*index as usize] = mark;
+                self.check_win(&board_copy) == Some(mark)
+            })
+            .collect()
+    }
+
+    /// Perfect play with a full minimax search, equally good moves are picked at random.
+    fn get_best_move(&self, player: Player, on_progress: &mut dyn FnMut(u16)) -> Option<u8>{
+        let my_mark = if player == Player::Player {self.player_mark} else {self.opponent_mark};
+        let enemy_mark = if player == Player::Player {self.opponent_mark} else {self.player_mark};
+
+        let mut best_score = i32::MIN;
+        let mut best_moves: Vec<u8> = Vec::new();
+        let candidates = Self::get_empty_fields(&self.board);
+        for (searched, index) in candidates.iter().copied().enumerate() {
+            let mut board_copy = self.board;
+            board_copy[index as usize] = my_mark;
+            let score = -self.negamax(&board_copy, enemy_mark, my_mark);
+
+            if score > best_score {
+                best_score = score;
+                best_moves.clear();
+            }
+            if score == best_score {
+                best_moves.push(index);
+            }
+            on_progress(((searched + 1) * 100 / candidates.len()) as u16);
+        }
+        best_moves.random()
+    }
+
+    /// Score of the board for the side to move, faster wins score higher.
+    fn negamax(&self, board: &[FieldMark; 9], to_move: FieldMark, other: FieldMark) -> i32{
+        if let Some(winner) = self.check_win(board) {
+            let empty_count = Self::get_empty_fields(board).len() as i32;
+            return if winner == FieldMark::Empty {0} else if winner == to_move {10 + empty_count} else {-10 - empty_count};
+        }
+
+        Self::get_empty_fields(board).into_iter()
+            .map(|index| {
+                let mut board_copy = *board;
+                board_copy[index as usize] = to_move;
+                -self.negamax(&board_copy, other, to_move)
+            })
+            .max()
+            .unwrap_or(0)
     }
 
+    /// Winner of `board`, `Empty` for a draw. In misère the mark that completed a line loses.
     fn check_win(&self, board: &[FieldMark; 9]) -> Option<FieldMark>{
+        let line_mark = Self::completed_line_mark(board);
+        match (line_mark, self.variant) {
+            (Some(FieldMark::X), Variant::Misere) => return Some(FieldMark::O),
+            (Some(FieldMark::O), Variant::Misere) => return Some(FieldMark::X),
+            (Some(mark), _) => return Some(mark),
+            (None, _) => {}
+        }
+
+        if board.iter().filter(|&mark| *mark != FieldMark::Empty).count() == 9{
+            return Some(FieldMark::Empty);
+        }
+
+        None
+    }
+
+    fn completed_line_mark(board: &[FieldMark; 9]) -> Option<FieldMark>{
         for i in 0..=2{
-            if(board[0+(3*i)] == board[1+(3*i)] && board[1+(3*i)] == board[2+(3*i)] && board[2+(3*i)] != FieldMark::Empty){
-                return Some(board[0+(3*i)]);
+            if board[3*i] == board[1+(3*i)] && board[1+(3*i)] == board[2+(3*i)] && board[2+(3*i)] != FieldMark::Empty {
+                return Some(board[3*i]);
             }
-            else if(board[0+i] == board[3+i] && board[3+i] == board[6+i] && board[6+i] != FieldMark::Empty){
-                return Some(board[0+i]);
+            else if board[i] == board[3+i] && board[3+i] == board[6+i] && board[6+i] != FieldMark::Empty {
+                return Some(board[i]);
             }
         }
 
         for i in 0..=1{
-            if(board[0 + (i*2)] == board[4] && board[4] == board[8 - (i*2)] && board[8- (i*2)] != FieldMark::Empty){
+            if board[i*2] == board[4] && board[4] == board[8 - (i*2)] && board[8- (i*2)] != FieldMark::Empty {
                 return Some(board[4]);
             }
         }
+        None
+    }
+}
 
-        if board.iter().filter(|&mark| *mark != FieldMark::Empty).count() == 9{
-            return Some(FieldMark::Empty);
-        }
+pub fn encode_board(board: &[FieldMark; 9]) -> String{
+    board.iter().map(|mark| mark.as_char()).collect()
+}
 
-        None
+pub fn decode_board(text: &str) -> Option<[FieldMark; 9]>{
+    let mut board = [FieldMark::Empty; 9];
+    if text.chars().count() != board.len() {
+        return None;
+    }
+    for (field, character) in board.iter_mut().zip(text.chars()) {
+        *field = FieldMark::from_char(character)?;
     }
-}
\ No newline at end of file
+    Some(board)
+}
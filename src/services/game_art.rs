@@ -1,4 +1,42 @@
+use std::cell::RefCell;
+use ratatui::style::Stylize;
 use ratatui::text::Text;
+use crate::enums::art_entry::ArtEntry;
+use crate::enums::banner::Banner;
+use crate::enums::language::Language;
+use crate::services::art_pack::ArtPack;
+use crate::services::locale;
+use crate::services::locale::tr;
+
+thread_local! {
+    //Art is drawn from free functions all over the views, so the selected pack lives here instead of in the settings
+    static ACTIVE_PACK: RefCell<ArtPack> = RefCell::new(ArtPack::built_in());
+}
+
+/// Makes every art function below prefer the entries of `pack`.
+pub fn use_pack(pack: ArtPack){
+    ACTIVE_PACK.with(|active| *active.borrow_mut() = pack);
+}
+
+fn art(entry: ArtEntry, built_in: &[&'static str]) -> Text<'static>{
+    ACTIVE_PACK.with(|active| match active.borrow().get(entry) {
+        Some(lines) => Text::from_iter(lines.iter().cloned()),
+        None => Text::from_iter(built_in.iter().copied()),
+    })
+}
+
+/// Screen readers spell art out character by character, in plain text mode titles are one bold line.
+pub fn title(art: Text<'static>, text: &str, plain_text: bool) -> Text<'static>{
+    if plain_text {Text::from(tr(text).to_string()).bold()} else {art}
+}
+
+/// Built in art with words in it comes in every language, the pictures do not.
+fn localized(english: &'static [&'static str], polish: &'static [&'static str]) -> &'static [&'static str]{
+    match locale::language() {
+        Language::English => english,
+        Language::Polish => polish,
+    }
+}
 
 
 const TIC_TAC_TOE: [&str; 6] = [
@@ -10,8 +48,17 @@ const TIC_TAC_TOE: [&str; 6] = [
     "    |_|  |_|\\___|    |_|\\__,_|\\___|    |_|\\___/ \\___|"
 ];
 
+const TIC_TAC_TOE_PL: [&str; 6] = [
+    " _  __     _ _           _   _  __                         _    ",
+    "| |/ /___ | | | _____   (_) | |/ /_ __ _____   _ _____   _| | __",
+    "| ' // _ \\| | |/ / _ \\  | | | ' /| '__|_  / | | |_  / | | | |/ /",
+    "| . \\ (_) | |   < (_) | | | | . \\| |   / /| |_| |/ /| |_| |   < ",
+    "|_|\\_\\___/|_|_|\\_\\___/  |_| |_|\\_\\_|  /___|\\__, /___|\\__, |_|\\_\\",
+    "                                           |___/     |___/      ",
+];
+
 pub fn tic_tac_toe() -> Text<'static>{
-    Text::from_iter(TIC_TAC_TOE)
+    art(ArtEntry::TicTacToe, localized(&TIC_TAC_TOE, &TIC_TAC_TOE_PL))
 }
 
 const SETTINGS: [&str; 6] = [
@@ -23,8 +70,16 @@ const SETTINGS: [&str; 6] = [
     "                            |___/     ",
 ];
 
+const SETTINGS_PL: [&str; 5] = [
+    " _   _     _                 _            _       ",
+    "| | | |___| |_ __ ___      _(_) ___ _ __ (_) __ _ ",
+    "| | | / __| __/ _` \\ \\ /\\ / / |/ _ \\ '_ \\| |/ _` |",
+    "| |_| \\__ \\ || (_| |\\ V  V /| |  __/ | | | | (_| |",
+    " \\___/|___/\\__\\__,_| \\_/\\_/ |_|\\___|_| |_|_|\\__,_|",
+];
+
 pub fn settings() -> Text<'static>{
-    Text::from_iter(SETTINGS)
+    art(ArtEntry::Settings, localized(&SETTINGS, &SETTINGS_PL))
 }
 
 const YOUR_TURN: [&str; 5] = [
@@ -35,8 +90,17 @@ const YOUR_TURN: [&str; 5] = [
     "  |_|\\___/ \\__,_|_|     \\__|\\__,_|_|  |_| |_|",
 ];
 
+const YOUR_TURN_PL: [&str; 6] = [
+    " _____               _         _                   ",
+    "|_   _|_      _____ (_) __ _  | |_ _   _ _ __ __ _ ",
+    "  | | \\ \\ /\\ / / _ \\| |/ _` | | __| | | | '__/ _` |",
+    "  | |  \\ V  V / (_) | | (_| | | |_| |_| | | | (_| |",
+    "  |_|   \\_/\\_/ \\___// |\\__,_|  \\__|\\__,_|_|  \\__,_|",
+    "                  |__/                             ",
+];
+
 pub fn your_turn() -> Text<'static>{
-    Text::from_iter(YOUR_TURN)
+    art(ArtEntry::YourTurn, localized(&YOUR_TURN, &YOUR_TURN_PL))
 }
 
 const ENEMY_TURN: [&str; 6] = [
@@ -48,8 +112,17 @@ const ENEMY_TURN: [&str; 6] = [
     "                            |___/                        "
 ];
 
+const ENEMY_TURN_PL: [&str; 6] = [
+    " _____                                           _       ",
+    "|_   _|   _ _ __ __ _   _ __ _   ___      ____ _| | __ _ ",
+    "  | || | | | '__/ _` | | '__| | | \\ \\ /\\ / / _` | |/ _` |",
+    "  | || |_| | | | (_| | | |  | |_| |\\ V  V / (_| | | (_| |",
+    "  |_| \\__,_|_|  \\__,_| |_|   \\__, | \\_/\\_/ \\__,_|_|\\__,_|",
+    "                             |___/                       ",
+];
+
 pub fn enemy_turn() -> Text<'static>{
-    Text::from_iter(ENEMY_TURN)
+    art(ArtEntry::EnemyTurn, localized(&ENEMY_TURN, &ENEMY_TURN_PL))
 }
 
 const CROSS_TURN: [&str; 5] = [
@@ -60,8 +133,17 @@ const CROSS_TURN: [&str; 5] = [
     " \\____|_|  \\___/|___/___/   |_| \\__,_|_|  |_| |_|"
 ];
 
+const CROSS_TURN_PL: [&str; 6] = [
+    " _____                  _                             _         ",
+    "|_   _|   _ _ __ __ _  | | ___ __ _____   _ _____   _| | ____ _ ",
+    "  | || | | | '__/ _` | | |/ / '__|_  / | | |_  / | | | |/ / _` |",
+    "  | || |_| | | | (_| | |   <| |   / /| |_| |/ /| |_| |   < (_| |",
+    "  |_| \\__,_|_|  \\__,_| |_|\\_\\_|  /___|\\__, /___|\\__, |_|\\_\\__,_|",
+    "                                      |___/     |___/           ",
+];
+
 pub fn cross_turn() -> Text<'static>{
-    Text::from_iter(CROSS_TURN)
+    art(ArtEntry::CrossTurn, localized(&CROSS_TURN, &CROSS_TURN_PL))
 }
 
 const CIRCLE_TURN: [&str; 5] = [
@@ -72,8 +154,16 @@ const CIRCLE_TURN: [&str; 5] = [
     " \\____|_|_|  \\___|_|\\___|   |_| \\__,_|_|  |_| |_|"
 ];
 
+const CIRCLE_TURN_PL: [&str; 5] = [
+    " _____                  _         _ _         ",
+    "|_   _|   _ _ __ __ _  | | _____ | | | ____ _ ",
+    "  | || | | | '__/ _` | | |/ / _ \\| | |/ / _` |",
+    "  | || |_| | | | (_| | |   < (_) | |   < (_| |",
+    "  |_| \\__,_|_|  \\__,_| |_|\\_\\___/|_|_|\\_\\__,_|",
+];
+
 pub fn circle_turn() -> Text<'static>{
-    Text::from_iter(CIRCLE_TURN)
+    art(ArtEntry::CircleTurn, localized(&CIRCLE_TURN, &CIRCLE_TURN_PL))
 }
 
 const YOU_WON: [&str; 5] = [
@@ -84,8 +174,17 @@ const YOU_WON: [&str; 5] = [
     "  |_|\\___/ \\__,_|   \\_/\\_/ \\___/|_| |_|",
 ];
 
+const YOU_WON_PL: [&str; 6] = [
+    "__        __                                 ",
+    "\\ \\      / /   _  __ _ _ __ __ _ _ __   __ _ ",
+    " \\ \\ /\\ / / | | |/ _` | '__/ _` | '_ \\ / _` |",
+    "  \\ V  V /| |_| | (_| | | | (_| | | | | (_| |",
+    "   \\_/\\_/  \\__, |\\__, |_|  \\__,_|_| |_|\\__,_|",
+    "           |___/ |___/                       ",
+];
+
 pub fn you_won() -> Text<'static>{
-    Text::from_iter(YOU_WON)
+    art(ArtEntry::YouWon, localized(&YOU_WON, &YOU_WON_PL))
 }
 
 const YOU_LOST: [&str; 5] = [
@@ -96,8 +195,17 @@ const YOU_LOST: [&str; 5] = [
     "  |_|\\___/ \\__,_| |_|\\___/|___/\\__|",
 ];
 
+const YOU_LOST_PL: [&str; 6] = [
+    " ____                                           ",
+    "|  _ \\ _ __ _______  __ _ _ __ __ _ _ __   __ _ ",
+    "| |_) | '__|_  / _ \\/ _` | '__/ _` | '_ \\ / _` |",
+    "|  __/| |   / /  __/ (_| | | | (_| | | | | (_| |",
+    "|_|   |_|  /___\\___|\\__, |_|  \\__,_|_| |_|\\__,_|",
+    "                    |___/                       ",
+];
+
 pub fn you_lost() -> Text<'static>{
-    Text::from_iter(YOU_LOST)
+    art(ArtEntry::YouLost, localized(&YOU_LOST, &YOU_LOST_PL))
 }
 
 const CIRCLE_WON: [&str; 5] = [
@@ -108,8 +216,17 @@ const CIRCLE_WON: [&str; 5] = [
     " \\____|_|_|  \\___|_|\\___|    \\_/\\_/ \\___/|_| |_|"
 ];
 
+const CIRCLE_WON_PL: [&str; 6] = [
+    " _  __     _ _                                          _       ",
+    "| |/ /___ | | | _____   __      ___   _  __ _ _ __ __ _| | ___  ",
+    "| ' // _ \\| | |/ / _ \\  \\ \\ /\\ / / | | |/ _` | '__/ _` | |/ _ \\ ",
+    "| . \\ (_) | |   < (_) |  \\ V  V /| |_| | (_| | | | (_| | | (_) |",
+    "|_|\\_\\___/|_|_|\\_\\___/    \\_/\\_/  \\__, |\\__, |_|  \\__,_|_|\\___/ ",
+    "                                  |___/ |___/                   ",
+];
+
 pub fn circle_won() -> Text<'static>{
-    Text::from_iter(CIRCLE_WON)
+    art(ArtEntry::CircleWon, localized(&CIRCLE_WON, &CIRCLE_WON_PL))
 }
 
 const CROSS_WON: [&str; 5] = [
@@ -120,8 +237,17 @@ const CROSS_WON: [&str; 5] = [
     " \\____|_|  \\___/|___/___/    \\_/\\_/ \\___/|_| |_|"
 ];
 
+const CROSS_WON_PL: [&str; 6] = [
+    " _  __                         _                                     _ ",
+    "| |/ /_ __ _____   _ _____   _| | __ __      ___   _  __ _ _ __ __ _| |",
+    "| ' /| '__|_  / | | |_  / | | | |/ / \\ \\ /\\ / / | | |/ _` | '__/ _` | |",
+    "| . \\| |   / /| |_| |/ /| |_| |   <   \\ V  V /| |_| | (_| | | | (_| | |",
+    "|_|\\_\\_|  /___|\\__, /___|\\__, |_|\\_\\   \\_/\\_/  \\__, |\\__, |_|  \\__,_|_|",
+    "               |___/     |___/                 |___/ |___/             ",
+];
+
 pub fn cross_won() -> Text<'static>{
-    Text::from_iter(CROSS_WON)
+    art(ArtEntry::CrossWon, localized(&CROSS_WON, &CROSS_WON_PL))
 }
 
 const DRAW: [&str; 5] = [
@@ -132,8 +258,16 @@ const DRAW: [&str; 5] = [
     "|____/|_| \\_\\/_/   \\_\\_/\\_/   "
 ];
 
+const DRAW_PL: [&str; 5] = [
+    " ____                _     ",
+    "|  _ \\ ___ _ __ ___ (_)___ ",
+    "| |_) / _ \\ '_ ` _ \\| / __|",
+    "|  _ <  __/ | | | | | \\__ \\",
+    "|_| \\_\\___|_| |_| |_|_|___/",
+];
+
 pub fn draw() -> Text<'static>{
-    Text::from_iter(DRAW)
+    art(ArtEntry::Draw, localized(&DRAW, &DRAW_PL))
 }
 
 
@@ -145,7 +279,7 @@ const X: [&str; 4] = [
 ];
 
 pub fn x() -> Text<'static>{
-    Text::from_iter(X)
+    art(ArtEntry::X, &X)
 }
 
 const O: [&str; 4] = [
@@ -156,7 +290,48 @@ const O: [&str; 4] = [
 ];
 
 pub fn o() -> Text<'static>{
-    Text::from_iter(O)
+    art(ArtEntry::O, &O)
+}
+
+//Bigger marks for large terminals
+const X_LARGE: [&str; 6] = [
+    "__   __",
+    "\\ \\ / /",
+    " \\ V / ",
+    "  > <  ",
+    " / . \\ ",
+    "/_/ \\_\\",
+];
+
+pub fn x_large() -> Text<'static>{
+    art(ArtEntry::XLarge, &X_LARGE)
+}
+
+const O_LARGE: [&str; 6] = [
+    "  ____  ",
+    " / __ \\ ",
+    "| |  | |",
+    "| |  | |",
+    "| |__| |",
+    " \\____/ ",
+];
+
+pub fn o_large() -> Text<'static>{
+    art(ArtEntry::OLarge, &O_LARGE)
+}
+
+pub fn banner(banner: Banner) -> Text<'static>{
+    match banner {
+        Banner::YourTurn => your_turn(),
+        Banner::EnemyTurn => enemy_turn(),
+        Banner::CrossTurn => cross_turn(),
+        Banner::CircleTurn => circle_turn(),
+        Banner::YouWon => you_won(),
+        Banner::YouLost => you_lost(),
+        Banner::CrossWon => cross_won(),
+        Banner::CircleWon => circle_won(),
+        Banner::Draw => draw(),
+    }
 }
 
 const ANGRY_FACE: [&str; 7] = [
@@ -170,7 +345,7 @@ const ANGRY_FACE: [&str; 7] = [
 ];
 
 pub fn angry_face() -> Text<'static> {
-    Text::from_iter(ANGRY_FACE)
+    art(ArtEntry::AngryFace, &ANGRY_FACE)
 }
 
 const SMILEY_FACE: [&str; 6] = [
@@ -183,7 +358,7 @@ const SMILEY_FACE: [&str; 6] = [
 ];
 
 pub fn smiley_face() -> Text<'static>{
-    Text::from_iter(SMILEY_FACE)
+    art(ArtEntry::SmileyFace, &SMILEY_FACE)
 }
 
 const THINKING_FACE: [&str; 7] = [
@@ -197,7 +372,7 @@ const THINKING_FACE: [&str; 7] = [
 ];
 
 pub fn thinking_face() -> Text<'static>{
-    Text::from_iter(THINKING_FACE)
+    art(ArtEntry::ThinkingFace, &THINKING_FACE)
 }
 
 const HAPPY_FACE: [&str; 6] = [
@@ -210,5 +385,93 @@ const HAPPY_FACE: [&str; 6] = [
 ];
 
 pub fn happy_face() -> Text<'static>{
-    Text::from_iter(HAPPY_FACE)
+    art(ArtEntry::HappyFace, &HAPPY_FACE)
+}
+
+/// Face of an AI personality, taken from its own pack when that has one.
+pub fn face(entry: ArtEntry, faces: Option<&ArtPack>) -> Text<'static>{
+    if let Some(lines) = faces.and_then(|pack| pack.get(entry)) {
+        return Text::from_iter(lines.iter().cloned());
+    }
+    match entry {
+        ArtEntry::AngryFace => angry_face(),
+        ArtEntry::ThinkingFace => thinking_face(),
+        ArtEntry::HappyFace => happy_face(),
+        _ => smiley_face(),
+    }
+}
+
+const JOIN: [&str; 5] = [
+    "     _       _       ",
+    "    | | ___ (_)_ __  ",
+    " _  | |/ _ \\| | '_ \\ ",
+    "| |_| | (_) | | | | |",
+    " \\___/ \\___/|_|_| |_|",
+];
+
+const JOIN_PL: [&str; 5] = [
+    " ____        _                ",
+    "|  _ \\  ___ | | __ _  ___ ____",
+    "| | | |/ _ \\| |/ _` |/ __|_  /",
+    "| |_| | (_) | | (_| | (__ / / ",
+    "|____/ \\___/|_|\\__,_|\\___/___|",
+];
+
+pub fn join() -> Text<'static>{
+    art(ArtEntry::Join, localized(&JOIN, &JOIN_PL))
+}
+
+const LOBBY: [&str; 6] = [
+    " _          _     _           ",
+    "| |    ___ | |__ | |__  _   _ ",
+    "| |   / _ \\| '_ \\| '_ \\| | | |",
+    "| |__| (_) | |_) | |_) | |_| |",
+    "|_____\\___/|_.__/|_.__/ \\__, |",
+    "                        |___/ ",
+];
+
+pub fn lobby() -> Text<'static>{
+    art(ArtEntry::Lobby, &LOBBY)
+}
+
+const PLAYERS: [&str; 6] = [
+    " ____  _                           ",
+    "|  _ \\| | __ _ _   _  ___ _ __ ___ ",
+    "| |_) | |/ _` | | | |/ _ \\ '__/ __|",
+    "|  __/| | (_| | |_| |  __/ |  \\__ \\",
+    "|_|   |_|\\__,_|\\__, |\\___|_|  |___/",
+    "               |___/               ",
+];
+
+const PLAYERS_PL: [&str; 5] = [
+    "  ____                        ",
+    " / ___|_ __ __ _  ___ _______ ",
+    "| |  _| '__/ _` |/ __|_  / _ \\",
+    "| |_| | | | (_| | (__ / /  __/",
+    " \\____|_|  \\__,_|\\___/___\\___|",
+];
+
+pub fn players() -> Text<'static>{
+    art(ArtEntry::Players, localized(&PLAYERS, &PLAYERS_PL))
+}
+
+const STATS: [&str; 5] = [
+    " ____  _        _       ",
+    "/ ___|| |_ __ _| |_ ___ ",
+    "\\___ \\| __/ _` | __/ __|",
+    " ___) | || (_| | |_\\__ \\",
+    "|____/ \\__\\__,_|\\__|___/",
+];
+
+const STATS_PL: [&str; 6] = [
+    " ____  _        _             _         _    _ ",
+    "/ ___|| |_ __ _| |_ _   _ ___| |_ _   _| | _(_)",
+    "\\___ \\| __/ _` | __| | | / __| __| | | | |/ / |",
+    " ___) | || (_| | |_| |_| \\__ \\ |_| |_| |   <| |",
+    "|____/ \\__\\__,_|\\__|\\__, |___/\\__|\\__, |_|\\_\\_|",
+    "                    |___/         |___/        ",
+];
+
+pub fn stats() -> Text<'static>{
+    art(ArtEntry::Stats, localized(&STATS, &STATS_PL))
 }
@@ -0,0 +1,226 @@
+use std::io;
+use std::path::Path;
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use crate::enums::key_action::KeyAction;
+use crate::services::locale::tr;
+use crate::services::storage;
+
+const KEYMAP_FILE: &str = "keymap.tsv";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyPreset{
+    Arrows,
+    Vim,
+    Wasd,
+    Numpad,
+    /// Any preset after a single key was rebound.
+    Custom,
+}
+
+impl KeyPreset{
+    pub fn as_str(&self) -> &str{
+        match self {
+            KeyPreset::Arrows => tr("Arrows"),
+            KeyPreset::Vim => "Vim (hjkl)",
+            KeyPreset::Wasd => "WASD",
+            KeyPreset::Numpad => tr("Numpad (1-9 tiles)"),
+            KeyPreset::Custom => tr("Custom"),
+        }
+    }
+
+    fn name(self) -> &'static str{
+        match self {
+            KeyPreset::Arrows => "arrows",
+            KeyPreset::Vim => "vim",
+            KeyPreset::Wasd => "wasd",
+            KeyPreset::Numpad => "numpad",
+            KeyPreset::Custom => "custom",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<KeyPreset>{
+        [KeyPreset::Arrows, KeyPreset::Vim, KeyPreset::Wasd, KeyPreset::Numpad, KeyPreset::Custom].into_iter()
+            .find(|preset| preset.name() == name)
+    }
+
+    pub fn next(&self) -> KeyPreset{
+        match self {
+            KeyPreset::Arrows => KeyPreset::Vim,
+            KeyPreset::Vim => KeyPreset::Wasd,
+            KeyPreset::Wasd => KeyPreset::Numpad,
+            KeyPreset::Numpad | KeyPreset::Custom => KeyPreset::Arrows,
+        }
+    }
+
+    pub fn previous(&self) -> KeyPreset{
+        match self {
+            KeyPreset::Arrows | KeyPreset::Custom => KeyPreset::Numpad,
+            KeyPreset::Vim => KeyPreset::Arrows,
+            KeyPreset::Wasd => KeyPreset::Vim,
+            KeyPreset::Numpad => KeyPreset::Wasd,
+        }
+    }
+}
+
+/// Which keys trigger which actions, one action can have several keys.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyMap{
+    pub preset: KeyPreset,
+    bindings: Vec<(KeyCode, KeyAction)>,
+}
+
+impl KeyMap{
+    pub fn from_preset(preset: KeyPreset) -> KeyMap{
+        let directions = match preset {
+            KeyPreset::Vim => [KeyCode::Char('k'), KeyCode::Char('j'), KeyCode::Char('h'), KeyCode::Char('l')],
+            KeyPreset::Wasd => [KeyCode::Char('w'), KeyCode::Char('s'), KeyCode::Char('a'), KeyCode::Char('d')],
+            _ => [KeyCode::Up, KeyCode::Down, KeyCode::Left, KeyCode::Right],
+        };
+        let mut bindings = vec![
+            (directions[0], KeyAction::Up),
+            (directions[1], KeyAction::Down),
+            (directions[2], KeyAction::Left),
+            (directions[3], KeyAction::Right),
+            (KeyCode::Enter, KeyAction::Confirm),
+            (KeyCode::Esc, KeyAction::Back),
+            (KeyCode::F(2), KeyAction::Messages),
+            (KeyCode::Char('?'), KeyAction::Help),
+            (KeyCode::F(1), KeyAction::Help),
+        ];
+        if preset == KeyPreset::Numpad {
+            //Laid out like a numpad, 7 is the top left tile and 3 the bottom right one
+            for (index, digit) in "789456123".chars().enumerate() {
+                bindings.push((KeyCode::Char(digit), KeyAction::Tile(index as u8)));
+            }
+        }
+
+        KeyMap{ preset, bindings }
+    }
+
+    pub fn action_for(&self, key: KeyEvent) -> Option<KeyAction>{
+        self.bindings.iter().find(|(bound, _)| *bound == key.code).map(|(_, action)| *action)
+    }
+
+    pub fn keys_for(&self, action: KeyAction) -> Vec<KeyCode>{
+        self.bindings.iter().filter(|(_, bound)| *bound == action).map(|(key, _)| *key).collect()
+    }
+
+    /// Short text like `↑` or `h` for the first key of the action, used in help texts.
+    pub fn label_for(&self, action: KeyAction) -> String{
+        self.keys_for(action).first().map(|key| key_label(*key)).unwrap_or("-".to_string())
+    }
+
+    /// Makes `key` the only key of `action`. An action that had `key` as its only key gets the old key
+    /// of `action` instead, so the two swap and no action is left without a key.
+    pub fn rebind(&mut self, action: KeyAction, key: KeyCode){
+        let old_keys = self.keys_for(action);
+        let displaced = self.bindings.iter().find(|(bound_key, _)| *bound_key == key).map(|(_, bound_action)| *bound_action);
+
+        self.bindings.retain(|(bound_key, bound_action)| *bound_action != action && *bound_key != key);
+        self.bindings.push((key, action));
+        if let Some(displaced) = displaced
+            && displaced != action
+            && self.keys_for(displaced).is_empty()
+            && let Some(old_key) = old_keys.first() {
+            self.bindings.push((*old_key, displaced));
+        }
+        self.preset = KeyPreset::Custom;
+    }
+
+    /// Loads the saved keymap, the arrows preset is used when nothing was saved or the file is broken.
+    pub fn load(data_dir: &Path) -> KeyMap{
+        let rows = storage::read_rows(&data_dir.join(KEYMAP_FILE)).unwrap_or_default();
+        let mut rows = rows.iter();
+        let Some(preset) = rows.next().and_then(|row| row.get(1)).and_then(|name| KeyPreset::from_name(name)) else {
+            return KeyMap::from_preset(KeyPreset::Arrows);
+        };
+        if preset != KeyPreset::Custom {
+            return KeyMap::from_preset(preset);
+        }
+
+        let mut bindings: Vec<(KeyCode, KeyAction)> = rows
+            .filter_map(|row| Some((parse_key(row.get(1)?)?, KeyAction::from_name(row.first()?)?)))
+            .collect();
+        if bindings.is_empty() {
+            return KeyMap::from_preset(KeyPreset::Arrows);
+        }
+        //Actions added after the keymap was saved keep their default key, unless it is taken
+        for (key, action) in KeyMap::from_preset(KeyPreset::Arrows).bindings {
+            if !bindings.iter().any(|(bound_key, bound_action)| *bound_key == key || *bound_action == action) {
+                bindings.push((key, action));
+            }
+        }
+        KeyMap{ preset, bindings }
+    }
+
+    pub fn save(&self, data_dir: &Path) -> io::Result<()>{
+        let mut rows = vec![vec!["preset".to_string(), self.preset.name().to_string()]];
+        rows.extend(self.bindings.iter().map(|(key, action)| vec![action.name(), key_name(*key)]));
+        storage::write_rows(&data_dir.join(KEYMAP_FILE), &rows)
+    }
+}
+
+/// Name of the key in the saved keymap.
+fn key_name(key: KeyCode) -> String{
+    match key {
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(character) => character.to_string(),
+        KeyCode::F(number) => format!("F{}", number),
+        KeyCode::Up => "Up".to_string(),
+        KeyCode::Down => "Down".to_string(),
+        KeyCode::Left => "Left".to_string(),
+        KeyCode::Right => "Right".to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::Backspace => "Backspace".to_string(),
+        KeyCode::Home => "Home".to_string(),
+        KeyCode::End => "End".to_string(),
+        KeyCode::PageUp => "PageUp".to_string(),
+        KeyCode::PageDown => "PageDown".to_string(),
+        KeyCode::Insert => "Insert".to_string(),
+        KeyCode::Delete => "Delete".to_string(),
+        other => format!("{:?}", other),
+    }
+}
+
+fn parse_key(name: &str) -> Option<KeyCode>{
+    let key = match name {
+        "Space" => KeyCode::Char(' '),
+        "Up" => KeyCode::Up,
+        "Down" => KeyCode::Down,
+        "Left" => KeyCode::Left,
+        "Right" => KeyCode::Right,
+        "Enter" => KeyCode::Enter,
+        "Esc" => KeyCode::Esc,
+        "Tab" => KeyCode::Tab,
+        "Backspace" => KeyCode::Backspace,
+        "Home" => KeyCode::Home,
+        "End" => KeyCode::End,
+        "PageUp" => KeyCode::PageUp,
+        "PageDown" => KeyCode::PageDown,
+        "Insert" => KeyCode::Insert,
+        "Delete" => KeyCode::Delete,
+        _ => {
+            let mut characters = name.chars();
+            match (characters.next(), characters.next()) {
+                (Some(character), None) => KeyCode::Char(character),
+                (Some('F'), Some(_)) => KeyCode::F(name[1..].parse().ok()?),
+                _ => return None,
+            }
+        }
+    };
+    Some(key)
+}
+
+/// Text shown to the player for a key, arrows are drawn as symbols.
+pub fn key_label(key: KeyCode) -> String{
+    match key {
+        KeyCode::Up => "↑".to_string(),
+        KeyCode::Down => "↓".to_string(),
+        KeyCode::Left => "←".to_string(),
+        KeyCode::Right => "→".to_string(),
+        KeyCode::Esc => "ESC".to_string(),
+        other => key_name(other),
+    }
+}
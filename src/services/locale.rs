@@ -0,0 +1,48 @@
+//! Translation of the interface text.
+//!
+//! English text is the key of every catalog entry. Enums keep two names for this: `as_str` stays English
+//! because the saved files and protocols use it, `label` passes it through [`tr`] for the screen.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Display;
+use crate::enums::language::Language;
+use crate::services::translations;
+
+struct Catalog{
+    language: Language,
+    messages: HashMap<&'static str, &'static str>,
+}
+
+thread_local! {
+    //Text is looked up from views, enums and services alike, so like the art pack the language lives here instead of in the settings
+    static ACTIVE: RefCell<Catalog> = RefCell::new(Catalog{ language: Language::English, messages: HashMap::new() });
+}
+
+/// Makes `tr` answer in `language` from now on.
+pub fn use_language(language: Language){
+    let messages = translations::catalog(language).iter().copied().collect();
+    ACTIVE.with(|active| *active.borrow_mut() = Catalog{ language, messages });
+}
+
+pub fn language() -> Language{
+    ACTIVE.with(|active| active.borrow().language)
+}
+
+/// `english` in the active language, text missing from the catalog stays English.
+pub fn tr(english: &str) -> &str{
+    ACTIVE.with(|active| active.borrow().messages.get(english).copied()).unwrap_or(english)
+}
+
+/// Translates `english` and fills its `{}` placeholders with `args` in order.
+pub fn tr_args(english: &str, args: &[&dyn Display]) -> String{
+    let mut parts = tr(english).split("{}");
+    let mut text = parts.next().unwrap_or_default().to_string();
+    for (index, part) in parts.enumerate() {
+        if let Some(arg) = args.get(index) {
+            text.push_str(&arg.to_string());
+        }
+        text.push_str(part);
+    }
+    text
+}
@@ -1,3 +1,21 @@
 pub mod game;
 pub mod game_art;
-pub mod settings;
\ No newline at end of file
+pub mod settings;
+pub mod engine;
+pub mod cli;
+pub mod network;
+pub mod discovery;
+pub mod storage;
+pub mod profiles;
+pub mod records;
+pub mod rating;
+pub mod clock;
+pub mod keymap;
+pub mod theme;
+pub mod art_pack;
+pub mod ai_worker;
+pub mod event_loop;
+pub mod crash_report;pub mod saved_game;
+pub mod locale;
+pub mod translations;
+pub mod personality;
@@ -0,0 +1,324 @@
+//! Two player games over TCP.
+//!
+//! The host owns the [`Game`](crate::services::game::Game) and validates every move, the joining
+//! side only sends moves and mirrors the state it receives. Anyone connecting after the opponent
+//! (or asking for it) becomes a read-only spectator. One message per line:
+//!
+//! ```text
+//! client -> host                     host -> client
+//! hello tictactoe <version> <role>   welcome <role> | reject <reason>
+//! move <0-8>                         state <board> <to_move> <winner>
+//!                                    spectators <count>
+//!                                    error <message>
+//! bye                                bye
+//! ```
+//!
+//! `<role>` is `player` or `spectator`, `<board>` uses the same encoding as the engine protocol,
+//! `<to_move>` is `x` or `o` and `<winner>` is `x`, `o`, `.` for a draw or `-` while the game is running.
+
+use std::fmt;
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+use std::net::{Ipv4Addr, Shutdown, SocketAddr, TcpListener, TcpStream};
+use std::sync::mpsc;
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::enums::field::FieldMark;
+use crate::services::discovery::DiscoveryResponder;
+use crate::services::event_loop;
+use crate::services::game::{decode_board, encode_board};
+use crate::services::locale::{tr, tr_args};
+
+pub const DEFAULT_PORT: u16 = 7878;
+pub const PROTOCOL_VERSION: u32 = 1;
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(3);
+/// Sends run on the interface thread, a peer that stopped reading fails the send instead of freezing the game.
+const WRITE_TIMEOUT: Duration = Duration::from_millis(500);
+
+#[derive(Debug)]
+pub enum NetworkError{
+    Io(String),
+    Disconnected,
+    Protocol(String),
+    Rejected(String),
+}
+
+impl fmt::Display for NetworkError{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result{
+        match self {
+            NetworkError::Io(message) => write!(f, "{}", tr_args("Network error: {}", &[message])),
+            NetworkError::Disconnected => write!(f, "{}", tr("Remote player disconnected")),
+            NetworkError::Protocol(line) => write!(f, "{}", tr_args("Remote side sent unexpected line: {}", &[line])),
+            NetworkError::Rejected(reason) => write!(f, "{}", tr_args("Host rejected the connection: {}", &[reason])),
+        }
+    }
+}
+
+impl std::error::Error for NetworkError {}
+
+impl From<std::io::Error> for NetworkError{
+    fn from(error: std::io::Error) -> Self{
+        NetworkError::Io(error.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role{
+    Player,
+    Spectator,
+}
+
+impl Role{
+    pub fn as_str(&self) -> &str{
+        match self {
+            Role::Player => "player",
+            Role::Spectator => "spectator",
+        }
+    }
+
+    fn parse(text: &str) -> Option<Role>{
+        match text {
+            "player" => Some(Role::Player),
+            "spectator" => Some(Role::Spectator),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message{
+    Hello{ version: u32, role: Role },
+    Welcome(Role),
+    Reject(String),
+    Move(u8),
+    State{ board: [FieldMark; 9], to_move: FieldMark, winner: Option<FieldMark> },
+    Spectators(usize),
+    Error(String),
+    Bye,
+}
+
+impl Message{
+    pub fn encode(&self) -> String{
+        match self {
+            Message::Hello { version, role } => format!("hello tictactoe {} {}", version, role.as_str()),
+            Message::Welcome(role) => format!("welcome {}", role.as_str()),
+            Message::Reject(reason) => format!("reject {}", reason),
+            Message::Move(index) => format!("move {}", index),
+            Message::State { board, to_move, winner } => {
+                let winner = winner.map(|mark| mark.as_char()).unwrap_or('-');
+                format!("state {} {} {}", encode_board(board), to_move.as_char(), winner)
+            }
+            Message::Spectators(count) => format!("spectators {}", count),
+            Message::Error(message) => format!("error {}", message),
+            Message::Bye => "bye".to_string(),
+        }
+    }
+
+    pub fn decode(line: &str) -> Result<Message, NetworkError>{
+        let protocol_error = || NetworkError::Protocol(line.to_string());
+        let (command, rest) = line.trim().split_once(' ').unwrap_or((line.trim(), ""));
+
+        match command {
+            "hello" => Self::decode_hello(rest).ok_or_else(protocol_error),
+            "welcome" => Role::parse(rest).map(Message::Welcome).ok_or_else(protocol_error),
+            "reject" => Ok(Message::Reject(rest.to_string())),
+            "move" => rest.parse().map(Message::Move).map_err(|_| protocol_error()),
+            "state" => Self::decode_state(rest).ok_or_else(protocol_error),
+            "spectators" => rest.parse().map(Message::Spectators).map_err(|_| protocol_error()),
+            "error" => Ok(Message::Error(rest.to_string())),
+            "bye" => Ok(Message::Bye),
+            _ => Err(protocol_error()),
+        }
+    }
+
+    fn decode_hello(text: &str) -> Option<Message>{
+        let mut parts = text.split(' ');
+        if parts.next()? != "tictactoe" {
+            return None;
+        }
+        let version = parts.next()?.parse().ok()?;
+        let role = Role::parse(parts.next()?)?;
+        Some(Message::Hello { version, role })
+    }
+
+    fn decode_state(text: &str) -> Option<Message>{
+        let mut parts = text.split(' ');
+        let board = decode_board(parts.next()?)?;
+        let to_move = FieldMark::from_char(parts.next()?.chars().next()?)?;
+        let winner = match parts.next()? {
+            "-" => None,
+            winner => Some(FieldMark::from_char(winner.chars().next()?)?),
+        };
+        Some(Message::State { board, to_move, winner })
+    }
+}
+
+/// One end of a connection, incoming lines are read on a background thread.
+pub struct Peer{
+    stream: TcpStream,
+    lines: Receiver<String>,
+    address: SocketAddr,
+}
+
+impl Peer{
+    fn new(stream: TcpStream) -> Result<Peer, NetworkError>{
+        stream.set_nonblocking(false)?;
+        stream.set_nodelay(true)?;
+        stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+        let address = stream.peer_addr()?;
+        let reader = stream.try_clone()?;
+
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move ||{
+            for line in BufReader::new(reader).lines() {
+                let Ok(line) = line else { break };
+                if sender.send(line).is_err() {
+                    break;
+                }
+                event_loop::wake();
+            }
+            //A closed connection is noticed on the next poll
+            drop(sender);
+            event_loop::wake();
+        });
+
+        Ok(Peer{ stream, lines: receiver, address })
+    }
+
+    pub fn address(&self) -> SocketAddr{
+        self.address
+    }
+
+    pub fn send(&mut self, message: &Message) -> Result<(), NetworkError>{
+        writeln!(self.stream, "{}", message.encode())?;
+        self.stream.flush()?;
+        Ok(())
+    }
+
+    /// Returns the next message if one already arrived, without blocking.
+    pub fn poll(&mut self) -> Result<Option<Message>, NetworkError>{
+        match self.lines.try_recv() {
+            Ok(line) => Message::decode(&line).map(Some),
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(TryRecvError::Disconnected) => Err(NetworkError::Disconnected),
+        }
+    }
+}
+
+impl Drop for Peer{
+    fn drop(&mut self){
+        let _ = self.send(&Message::Bye);
+        let _ = self.stream.shutdown(Shutdown::Both);
+    }
+}
+
+/// Listening side of a game, the first player to say hello becomes the opponent, everyone else spectates.
+pub struct HostSession{
+    listener: TcpListener,
+    handshakes: Vec<(Peer, Instant)>,
+    responder: Option<DiscoveryResponder>,
+    pub opponent: Option<Peer>,
+    pub spectators: Vec<Peer>,
+}
+
+impl HostSession{
+    pub fn bind(port: u16) -> Result<HostSession, NetworkError>{
+        let listener = TcpListener::bind((Ipv4Addr::UNSPECIFIED, port))?;
+        listener.set_nonblocking(true)?;
+        Ok(HostSession{ listener, handshakes: Vec::new(), responder: None, opponent: None, spectators: Vec::new() })
+    }
+
+    /// Makes the game visible in lobbies, when the discovery port is taken the game is just not listed.
+    pub fn advertise(&mut self, discovery_port: u16, host_name: String){
+        self.responder = DiscoveryResponder::bind(discovery_port, self.port(), host_name).ok();
+    }
+
+    pub fn port(&self) -> u16{
+        self.listener.local_addr().map(|address| address.port()).unwrap_or(0)
+    }
+
+    /// Accepts new connections and drops leaving spectators without blocking,
+    /// returns true when someone joined or left so the state can be sent again.
+    pub fn update_connections(&mut self) -> Result<bool, NetworkError>{
+        if let Some(responder) = &self.responder {
+            responder.answer_queries(self.opponent.is_none());
+        }
+
+        loop {
+            match self.listener.accept() {
+                Ok((stream, _)) => {
+                    if let Ok(peer) = Peer::new(stream) {
+                        self.handshakes.push((peer, Instant::now()));
+                    }
+                }
+                Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+                Err(error) => return Err(error.into()),
+            }
+        }
+
+        let mut changed = false;
+        for (mut peer, connected_at) in std::mem::take(&mut self.handshakes) {
+            //A client that fails the handshake is simply dropped
+            match peer.poll() {
+                Ok(Some(Message::Hello { version, role })) => {
+                    if version != PROTOCOL_VERSION {
+                        let _ = peer.send(&Message::Reject(format!("unsupported version {}", version)));
+                    }
+                    else if role == Role::Player && self.opponent.is_none() {
+                        if peer.send(&Message::Welcome(Role::Player)).is_ok() {
+                            self.opponent = Some(peer);
+                            changed = true;
+                        }
+                    }
+                    else if peer.send(&Message::Welcome(Role::Spectator)).is_ok() {
+                        self.spectators.push(peer);
+                        changed = true;
+                    }
+                }
+                Ok(None) if connected_at.elapsed() < HANDSHAKE_TIMEOUT => self.handshakes.push((peer, connected_at)),
+                _ => {}
+            }
+        }
+
+        let spectator_count = self.spectators.len();
+        self.spectators.retain_mut(Self::is_spectator_connected);
+        Ok(changed || spectator_count != self.spectators.len())
+    }
+
+    fn is_spectator_connected(peer: &mut Peer) -> bool{
+        loop {
+            match peer.poll() {
+                Ok(None) => return true,
+                Ok(Some(Message::Move(_))) => {
+                    if peer.send(&Message::Error("Spectators cannot move".to_string())).is_err() {
+                        return false;
+                    }
+                }
+                Ok(Some(Message::Bye)) | Err(_) => return false,
+                Ok(Some(_)) => {}
+            }
+        }
+    }
+
+    /// Sends the message to the opponent and all spectators, spectators that cannot be reached are dropped.
+    pub fn broadcast(&mut self, message: &Message) -> Result<(), NetworkError>{
+        self.spectators.retain_mut(|peer| peer.send(message).is_ok());
+        match self.opponent.as_mut() {
+            Some(peer) => peer.send(message),
+            None => Ok(()),
+        }
+    }
+}
+
+pub enum RemoteSession{
+    Host(HostSession),
+    Client(Peer),
+}
+
+/// Connects to a host and says hello, the answer (`welcome` or `reject`) arrives through [`Peer::poll`].
+pub fn join(address: SocketAddr, role: Role) -> Result<Peer, NetworkError>{
+    let stream = TcpStream::connect_timeout(&address, HANDSHAKE_TIMEOUT)?;
+    let mut peer = Peer::new(stream)?;
+    peer.send(&Message::Hello { version: PROTOCOL_VERSION, role })?;
+    Ok(peer)
+}
@@ -0,0 +1,176 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use ratatui::text::Text;
+use crate::enums::ai_level::AiLevel;
+use crate::enums::field::FieldMark;
+use crate::enums::situation::Situation;
+use crate::helpers::vector_helper::VecExt;
+use crate::services::art_pack::ArtPack;
+use crate::services::game::Game;
+use crate::services::locale::tr_args;
+use crate::services::storage;
+use crate::services::game_art as Art;
+
+pub const DEFAULT_PERSONALITY: &str = "Classic";
+const PERSONALITY_DIR: &str = "personalities";
+
+/// AI opponent with a level, faces and lines, built in or read from `<data dir>/personalities/<file>.tsv`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Personality{
+    pub name: String,
+    /// Decides the strategy, and the rating the games count against.
+    pub level: AiLevel,
+    /// Art pack the faces are taken from, faces it does not have stay built in.
+    faces: Option<ArtPack>,
+    lines: Vec<(Situation, String)>,
+}
+
+impl Personality{
+    fn from_lines(name: &str, level: AiLevel, lines: &[(Situation, &str)]) -> Personality{
+        Personality{
+            name: name.to_string(),
+            level,
+            faces: None,
+            lines: lines.iter().map(|(situation, line)| (*situation, line.to_string())).collect(),
+        }
+    }
+
+    pub fn classic() -> Personality{
+        Personality::from_lines(DEFAULT_PERSONALITY, AiLevel::Normal, &[
+            (Situation::Waiting, "waiting for turn.."),
+            (Situation::Thinking, "calculating move"),
+            (Situation::Blocking, "Not so fast"),
+            (Situation::Blunder, "Are you sure about that?"),
+            (Situation::Winning, "Yay i won, you suck"),
+            (Situation::Losing, "I will remember that"),
+            (Situation::Draw, "You are as bad as me"),
+        ])
+    }
+
+    pub fn get_built_in() -> Vec<Personality>{
+        vec![
+            Personality::classic(),
+            Personality::from_lines("Rookie", AiLevel::Easy, &[
+                (Situation::Waiting, "Take your time"),
+                (Situation::Waiting, "I am still learning"),
+                (Situation::Thinking, "Eeny, meeny, miny..."),
+                (Situation::Thinking, "Hmm, this one?"),
+                (Situation::Blocking, "Oh, I saw that one!"),
+                (Situation::Blunder, "Was that a trick?"),
+                (Situation::Winning, "I won? I won!"),
+                (Situation::Losing, "Well played, teach me that one"),
+                (Situation::Draw, "Nobody lost, nice"),
+            ]),
+            Personality::from_lines("Grandmaster", AiLevel::Hard, &[
+                (Situation::Waiting, "Take all the time you need"),
+                (Situation::Thinking, "Calculating every line"),
+                (Situation::Thinking, "Searching the tree"),
+                (Situation::Blocking, "Predictable"),
+                (Situation::Blunder, "A mistake, and you know it"),
+                (Situation::Winning, "As expected"),
+                (Situation::Losing, "Impossible..."),
+                (Situation::Draw, "The only correct result"),
+            ]),
+        ]
+    }
+
+    /// Built in personalities first, then the files in alphabetical order. Skipped files are returned with the reason.
+    pub fn available(data_dir: &Path) -> (Vec<Personality>, Vec<String>){
+        let mut personalities = Personality::get_built_in();
+        let mut problems = Vec::new();
+
+        let mut paths: Vec<PathBuf> = fs::read_dir(data_dir.join(PERSONALITY_DIR))
+            .map(|entries| entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.extension().is_some_and(|extension| extension == "tsv"))
+                .collect())
+            .unwrap_or_default();
+        paths.sort();
+
+        for path in paths {
+            let file_name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            match Personality::load(data_dir, &path) {
+                Ok(personality) if personalities.iter().any(|known| known.name == personality.name) => {
+                    problems.push(tr_args("{}: the name {} is taken", &[&file_name, &personality.name]));
+                }
+                Ok(personality) => personalities.push(personality),
+                Err(problem) => problems.push(format!("{}: {}", file_name, problem)),
+            }
+        }
+        (personalities, problems)
+    }
+
+    /// Personality called `name`, the classic one when it is gone.
+    pub fn find(data_dir: &Path, name: &str) -> Personality{
+        Personality::available(data_dir).0.into_iter()
+            .find(|personality| personality.name == name)
+            .unwrap_or(Personality::classic())
+    }
+
+    /// Rows are `name`, `level`, `faces` (an art pack) or a situation followed by one or more lines.
+    /// Situations without lines use the lines of the classic personality.
+    fn load(data_dir: &Path, path: &Path) -> Result<Personality, String>{
+        let rows = storage::read_rows(path).map_err(|error| error.to_string())?;
+        let name = path.file_stem().map(|stem| stem.to_string_lossy().to_string()).unwrap_or_default();
+        let mut personality = Personality{ name, level: AiLevel::Normal, faces: None, lines: Vec::new() };
+
+        for row in rows {
+            let Some((key, values)) = row.split_first() else { continue };
+            let value = values.first().map(|value| value.trim()).unwrap_or_default();
+            match key.as_str() {
+                "name" if !value.is_empty() => personality.name = storage::sanitize(value),
+                "level" => {
+                    personality.level = AiLevel::from_str(value).ok_or_else(|| tr_args("unknown AI level {}", &[&value]))?;
+                }
+                "faces" => {
+                    let pack = ArtPack::load(data_dir, value).map_err(|_| tr_args("art pack {} not found", &[&value]))?;
+                    personality.faces = Some(pack);
+                }
+                other => {
+                    let situation = Situation::from_str(other).ok_or_else(|| tr_args("unknown row {}", &[&other]))?;
+                    let lines = values.iter().map(|line| line.trim()).filter(|line| !line.is_empty());
+                    personality.lines.extend(lines.map(|line| (situation, line.to_string())));
+                }
+            }
+        }
+
+        for (situation, line) in Personality::classic().lines {
+            if !personality.lines.iter().any(|(known, _)| *known == situation) {
+                personality.lines.push((situation, line));
+            }
+        }
+        Ok(personality)
+    }
+
+    /// One of the lines for `situation`, picked at random.
+    pub fn line(&self, situation: Situation) -> String{
+        let lines: Vec<&String> = self.lines.iter().filter(|(known, _)| *known == situation).map(|(_, line)| line).collect();
+        lines.random().cloned().unwrap_or_default()
+    }
+
+    pub fn face(&self, situation: Situation) -> Text<'static>{
+        Art::face(situation.face(), self.faces.as_ref())
+    }
+}
+
+/// Blocks by the AI and blunders by the player, judged against the board before the last move.
+pub fn judge_last_move(game: &Game) -> Option<Situation>{
+    let last = *game.moves.last()?;
+    if game.winner.is_some() {
+        return None;
+    }
+    let mark = game.board[last as usize];
+    let other = if mark == game.player_mark {game.opponent_mark} else {game.player_mark};
+    let mut before = game.board;
+    before[last as usize] = FieldMark::Empty;
+
+    if mark == game.opponent_mark {
+        //The AI took the field the player would have won on
+        game.winning_moves(&before, other).contains(&last).then_some(Situation::Blocking)
+    }else{
+        //The player missed a win or left one to the AI
+        let missed = !game.winning_moves(&before, mark).is_empty();
+        let allowed = !game.winning_moves(&game.board, other).is_empty();
+        (missed || allowed).then_some(Situation::Blunder)
+    }
+}
@@ -0,0 +1,229 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use crate::enums::ai_level::AiLevel;
+use crate::enums::outcome::Outcome;
+use crate::enums::player::Player;
+use crate::services::locale::{tr, tr_args};
+use crate::services::rating;
+use crate::services::rating::INITIAL_RATING;
+use crate::services::storage;
+
+pub const MAX_NAME_LENGTH: usize = 20;
+const PROFILES_FILE: &str = "profiles.tsv";
+const AI_RATINGS_FILE: &str = "ai_ratings.tsv";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profile{
+    pub name: String,
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    pub rating: f64,
+}
+
+impl Profile{
+    pub fn new(name: &str) -> Profile{
+        Profile{ name: name.to_string(), wins: 0, losses: 0, draws: 0, rating: INITIAL_RATING }
+    }
+
+    pub fn games(&self) -> u32{
+        self.wins + self.losses + self.draws
+    }
+
+    fn from_row(row: &[String]) -> Option<Profile>{
+        let number = |index: usize| row.get(index).and_then(|value| value.parse().ok()).unwrap_or(0);
+        let name = row.first()?.trim();
+        if name.is_empty() {
+            return None;
+        }
+        let rating = row.get(4).and_then(|value| value.parse().ok()).unwrap_or(INITIAL_RATING);
+
+        Some(Profile{ name: name.to_string(), wins: number(1), losses: number(2), draws: number(3), rating })
+    }
+
+    fn to_row(&self) -> Vec<String>{
+        vec![
+            storage::sanitize(&self.name),
+            self.wins.to_string(),
+            self.losses.to_string(),
+            self.draws.to_string(),
+            format!("{:.1}", self.rating),
+        ]
+    }
+}
+
+/// Rating of one AI difficulty, AI levels are rated like players so both can be compared.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AiRating{
+    pub level: AiLevel,
+    pub rating: f64,
+    pub games: u32,
+}
+
+impl AiRating{
+    fn from_row(row: &[String]) -> Option<AiRating>{
+        Some(AiRating{
+            level: AiLevel::from_str(row.first()?)?,
+            rating: row.get(1)?.parse().ok()?,
+            games: row.get(2).and_then(|value| value.parse().ok()).unwrap_or(0),
+        })
+    }
+
+    fn to_row(&self) -> Vec<String>{
+        vec![self.level.as_str().to_string(), format!("{:.1}", self.rating), self.games.to_string()]
+    }
+}
+
+/// Saved player profiles and AI ratings, and which profile sits on which seat for the next game.
+pub struct ProfileStore{
+    path: PathBuf,
+    ai_ratings_path: PathBuf,
+    pub profiles: Vec<Profile>,
+    pub ai_ratings: Vec<AiRating>,
+    player_seat: Option<usize>,
+    opponent_seat: Option<usize>,
+}
+
+impl ProfileStore{
+    pub fn load(data_dir: &Path) -> ProfileStore{
+        let path = data_dir.join(PROFILES_FILE);
+        let ai_ratings_path = data_dir.join(AI_RATINGS_FILE);
+        //An unreadable file starts an empty list, it is only overwritten once a profile changes
+        let profiles = storage::read_rows(&path)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|row| Profile::from_row(row))
+            .collect();
+
+        let saved_ai_ratings: Vec<AiRating> = storage::read_rows(&ai_ratings_path)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|row| AiRating::from_row(row))
+            .collect();
+        let ai_ratings = AiLevel::get_all().into_iter()
+            .map(|level| {
+                saved_ai_ratings.iter().find(|saved| saved.level == level).cloned()
+                    .unwrap_or(AiRating{ level, rating: INITIAL_RATING, games: 0 })
+            })
+            .collect();
+
+        ProfileStore{ path, ai_ratings_path, profiles, ai_ratings, player_seat: None, opponent_seat: None }
+    }
+
+    pub fn save(&self) -> io::Result<()>{
+        let rows: Vec<Vec<String>> = self.profiles.iter().map(Profile::to_row).collect();
+        storage::write_rows(&self.path, &rows)?;
+
+        let ai_rows: Vec<Vec<String>> = self.ai_ratings.iter().map(AiRating::to_row).collect();
+        storage::write_rows(&self.ai_ratings_path, &ai_rows)
+    }
+
+    pub fn ai_rating(&self, level: AiLevel) -> f64{
+        self.ai_ratings.iter().find(|ai| ai.level == level).map(|ai| ai.rating).unwrap_or(INITIAL_RATING)
+    }
+
+    /// Creates and saves a new profile, returns its index.
+    pub fn add(&mut self, name: &str) -> Result<usize, String>{
+        let name = storage::sanitize(name.trim());
+        if name.is_empty() {
+            return Err(tr("Name cannot be empty").to_string());
+        }
+        if name.chars().count() > MAX_NAME_LENGTH {
+            return Err(tr_args("Name can have at most {} characters", &[&MAX_NAME_LENGTH]));
+        }
+        if self.profiles.iter().any(|profile| profile.name.eq_ignore_ascii_case(&name)) {
+            return Err(tr_args("Profile {} already exists", &[&name]));
+        }
+
+        self.profiles.push(Profile::new(&name));
+        self.save().map_err(|error| tr_args("Could not save profiles: {}", &[&error]))?;
+        Ok(self.profiles.len() - 1)
+    }
+
+    pub fn seat(&self, player: Player) -> Option<&Profile>{
+        let index = match player {
+            Player::Player => self.player_seat,
+            Player::Opponent => self.opponent_seat,
+        };
+        index.and_then(|index| self.profiles.get(index))
+    }
+
+    pub fn seat_index(&self, player: Player) -> Option<usize>{
+        match player {
+            Player::Player => self.player_seat,
+            Player::Opponent => self.opponent_seat,
+        }
+    }
+
+    pub fn set_seat(&mut self, player: Player, index: Option<usize>){
+        let index = index.filter(|index| *index < self.profiles.len());
+        match player {
+            Player::Player => self.player_seat = index,
+            Player::Opponent => self.opponent_seat = index,
+        }
+    }
+
+    /// Moves the seat to the next profile, after the last profile the seat becomes a guest again.
+    pub fn cycle_seat(&mut self, player: Player, forward: bool){
+        let count = self.profiles.len();
+        //Guest is treated as position `count`, one after the last profile
+        let current = self.seat_index(player).unwrap_or(count);
+        let next = if forward {(current + 1) % (count + 1)} else {(current + count) % (count + 1)};
+        self.set_seat(player, if next == count {None} else {Some(next)});
+    }
+
+    pub fn seat_name(&self, player: Player) -> String{
+        match self.seat(player) {
+            Some(profile) => profile.name.clone(),
+            None => tr("Guest").to_string(),
+        }
+    }
+
+    /// Name with the rating, as shown when choosing seats.
+    pub fn seat_label(&self, player: Player) -> String{
+        match self.seat(player) {
+            Some(profile) => format!("{} ({:.0})", profile.name, profile.rating),
+            None => tr("Guest").to_string(),
+        }
+    }
+
+    /// Adds the outcome to the profile on the seat, guests are not tracked.
+    pub fn record_outcome(&mut self, player: Player, outcome: Outcome){
+        let Some(index) = self.seat_index(player) else { return };
+        let Some(profile) = self.profiles.get_mut(index) else { return };
+
+        match outcome {
+            Outcome::Win => profile.wins += 1,
+            Outcome::Loss => profile.losses += 1,
+            Outcome::Draw => profile.draws += 1,
+        }
+    }
+
+    /// Rates a game between both seats, returns the new ratings or None when a seat is a guest.
+    pub fn rate_seats(&mut self, player_outcome: Outcome, opponent_outcome: Outcome) -> Option<(f64, f64)>{
+        let player_index = self.seat_index(Player::Player)?;
+        let opponent_index = self.seat_index(Player::Opponent)?;
+        if player_index == opponent_index {
+            return None;
+        }
+
+        let player_rating = self.profiles[player_index].rating;
+        let opponent_rating = self.profiles[opponent_index].rating;
+        self.profiles[player_index].rating = rating::updated_rating(player_rating, opponent_rating, player_outcome);
+        self.profiles[opponent_index].rating = rating::updated_rating(opponent_rating, player_rating, opponent_outcome);
+        Some((self.profiles[player_index].rating, self.profiles[opponent_index].rating))
+    }
+
+    /// Rates a game of the player seat against an AI level, returns the new ratings or None for a guest.
+    pub fn rate_against_ai(&mut self, level: AiLevel, player_outcome: Outcome, ai_outcome: Outcome) -> Option<(f64, f64)>{
+        let player_index = self.seat_index(Player::Player)?;
+        let ai = self.ai_ratings.iter_mut().find(|ai| ai.level == level)?;
+        let profile = self.profiles.get_mut(player_index)?;
+
+        let player_rating = profile.rating;
+        profile.rating = rating::updated_rating(player_rating, ai.rating, player_outcome);
+        ai.rating = rating::updated_rating(ai.rating, player_rating, ai_outcome);
+        ai.games += 1;
+        Some((profile.rating, ai.rating))
+    }
+}
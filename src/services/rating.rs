@@ -0,0 +1,14 @@
+use crate::enums::outcome::Outcome;
+
+pub const INITIAL_RATING: f64 = 1200.0;
+const K_FACTOR: f64 = 32.0;
+
+/// Probability (0-1) that a player rated `rating` beats one rated `opponent_rating`.
+pub fn expected_score(rating: f64, opponent_rating: f64) -> f64{
+    1.0 / (1.0 + 10f64.powf((opponent_rating - rating) / 400.0))
+}
+
+/// Elo update for one side of a game, call it for both players with their own outcome.
+pub fn updated_rating(rating: f64, opponent_rating: f64, outcome: Outcome) -> f64{
+    rating + K_FACTOR * (outcome.score() - expected_score(rating, opponent_rating))
+}
@@ -0,0 +1,97 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::enums::field::FieldMark;
+use crate::enums::player_type::PlayerType;
+use crate::services::storage;
+
+const RECORDS_FILE: &str = "games.tsv";
+
+/// One finished game, appended to the records file as a single row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameRecord{
+    pub finished_at: u64,
+    /// Kind of opponent, stored with [`PlayerType::as_str`].
+    pub mode: PlayerType,
+    pub cross_name: String,
+    pub circle_name: String,
+    pub winner: FieldMark,
+    pub moves: Vec<u8>,
+    /// Ratings after the game, only present when both sides were rated.
+    pub ratings: Option<(f64, f64)>,
+}
+
+impl GameRecord{
+    pub fn new(mode: PlayerType, cross_name: &str, circle_name: &str, winner: FieldMark, moves: &[u8]) -> GameRecord{
+        let finished_at = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_secs()).unwrap_or(0);
+
+        GameRecord{
+            finished_at,
+            mode,
+            cross_name: cross_name.to_string(),
+            circle_name: circle_name.to_string(),
+            winner,
+            moves: moves.to_vec(),
+            ratings: None,
+        }
+    }
+
+    fn from_row(row: &[String]) -> Option<GameRecord>{
+        let rating = |index: usize| row.get(index).and_then(|value| value.parse::<f64>().ok());
+        let moves = row.get(5)?.chars()
+            .map(|character| character.to_digit(10).map(|digit| digit as u8))
+            .collect::<Option<Vec<u8>>>()?;
+
+        Some(GameRecord{
+            finished_at: row.first()?.parse().ok()?,
+            mode: PlayerType::from_str(row.get(1)?)?,
+            cross_name: row.get(2)?.clone(),
+            circle_name: row.get(3)?.clone(),
+            winner: FieldMark::from_char(row.get(4)?.chars().next()?)?,
+            moves,
+            ratings: rating(6).zip(rating(7)),
+        })
+    }
+
+    fn to_row(&self) -> Vec<String>{
+        let mut row = vec![
+            self.finished_at.to_string(),
+            self.mode.as_str().to_string(),
+            storage::sanitize(&self.cross_name),
+            storage::sanitize(&self.circle_name),
+            self.winner.as_char().to_string(),
+            self.moves.iter().map(|index| index.to_string()).collect(),
+        ];
+        if let Some((cross_rating, circle_rating)) = self.ratings {
+            row.push(format!("{:.1}", cross_rating));
+            row.push(format!("{:.1}", circle_rating));
+        }
+        row
+    }
+
+    /// Rating the named side had after this game, if it was rated.
+    pub fn rating_of(&self, name: &str) -> Option<f64>{
+        let (cross_rating, circle_rating) = self.ratings?;
+        if self.cross_name == name {
+            Some(cross_rating)
+        }else if self.circle_name == name {
+            Some(circle_rating)
+        }else{
+            None
+        }
+    }
+}
+
+fn records_path(data_dir: &Path) -> PathBuf{
+    data_dir.join(RECORDS_FILE)
+}
+
+pub fn append(data_dir: &Path, record: &GameRecord) -> io::Result<()>{
+    storage::append_row(&records_path(data_dir), &record.to_row())
+}
+
+/// All saved games, oldest first, rows that cannot be parsed are skipped.
+pub fn load_all(data_dir: &Path) -> io::Result<Vec<GameRecord>>{
+    let rows = storage::read_rows(&records_path(data_dir))?;
+    Ok(rows.iter().filter_map(|row| GameRecord::from_row(row)).collect())
+}
@@ -0,0 +1,71 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::enums::ai_level::AiLevel;
+use crate::enums::field::FieldMark;
+use crate::enums::player_type::PlayerType;
+use crate::enums::variant::Variant;
+use crate::services::personality::DEFAULT_PERSONALITY;
+use crate::services::storage;
+
+const SAVED_GAME_FILE: &str = "saved_game.tsv";
+
+/// Unfinished local game saved from the pause menu, there is at most one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavedGame{
+    pub opponent_type: PlayerType,
+    pub ai_level: AiLevel,
+    pub moves: Vec<u8>,
+    pub personality: String,
+    pub variant: Variant,
+    /// Mark of the local player, circle when the AI moved first.
+    pub player_mark: FieldMark,
+}
+
+impl SavedGame{
+    fn from_row(row: &[String]) -> Option<SavedGame>{
+        let moves = row.get(2)?.chars()
+            .map(|character| character.to_digit(10).map(|digit| digit as u8))
+            .collect::<Option<Vec<u8>>>()?;
+
+        Some(SavedGame{
+            opponent_type: PlayerType::from_str(row.first()?)?,
+            ai_level: AiLevel::from_str(row.get(1)?)?,
+            moves,
+            //Games saved before personalities existed have no name
+            personality: row.get(3).cloned().unwrap_or(DEFAULT_PERSONALITY.to_string()),
+            //Older games were classic ones started by the player
+            variant: row.get(4).and_then(|text| Variant::from_str(text)).unwrap_or(Variant::Classic),
+            player_mark: row.get(5).and_then(|text| text.chars().next()).and_then(FieldMark::from_char).unwrap_or(FieldMark::X),
+        })
+    }
+
+    fn to_row(&self) -> Vec<String>{
+        vec![
+            self.opponent_type.as_str().to_string(),
+            self.ai_level.as_str().to_string(),
+            self.moves.iter().map(|index| index.to_string()).collect(),
+            storage::sanitize(&self.personality),
+            self.variant.as_str().to_string(),
+            self.player_mark.as_char().to_string(),
+        ]
+    }
+}
+
+/// Replaces the saved game.
+pub fn save(data_dir: &Path, game: &SavedGame) -> io::Result<()>{
+    storage::write_rows(&data_dir.join(SAVED_GAME_FILE), &[game.to_row()])
+}
+
+/// The saved game, None when there is none or the file is broken.
+pub fn load(data_dir: &Path) -> Option<SavedGame>{
+    let rows = storage::read_rows(&data_dir.join(SAVED_GAME_FILE)).ok()?;
+    rows.first().and_then(|row| SavedGame::from_row(row))
+}
+
+pub fn remove(data_dir: &Path) -> io::Result<()>{
+    match fs::remove_file(data_dir.join(SAVED_GAME_FILE)) {
+        Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+        _ => Ok(()),
+    }
+}
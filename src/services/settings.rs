@@ -1,21 +1,155 @@
-use ratatui::style::Color;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+use crate::enums::first_move::FirstMove;
+use crate::enums::language::Language;
+use crate::enums::variant::Variant;
+use crate::services::art_pack::{ArtPack, DEFAULT_PACK};
+use crate::services::clock::TimeControl;
+use crate::services::engine::EngineConfig;
+use crate::services::keymap::{KeyMap, KeyPreset};
+use crate::services::discovery::DISCOVERY_PORT;
+use crate::services::network::DEFAULT_PORT;
+use crate::services::personality::Personality;
+use crate::services::storage;
+use crate::services::theme::Theme;
+
+const PREFERENCES_FILE: &str = "settings.tsv";
+//The built in AI answers instantly, a short pause makes its moves easier to follow
+pub const AI_THINK_TIME: Duration = Duration::from_millis(1000);
+/// Longest AI think delay the settings screen offers.
+pub const MAX_AI_THINK_TIME: Duration = Duration::from_millis(3000);
 
 pub struct Settings{
-    pub player_color: Color,
-    pub opponent_color: Color,
+    pub engine: Option<EngineConfig>,
+    pub network_port: u16,
+    pub discovery_port: u16,
+    pub data_dir: PathBuf,
+    /// AI opponent of the next game, chosen on the seats or the settings screen.
+    pub personality: Personality,
+    pub first_move: FirstMove,
+    pub variant: Variant,
+    /// Shortest time the AI takes for a move, so it does not answer instantly.
+    pub ai_think_time: Duration,
+    pub time_control: TimeControl,
+    pub keymap: KeyMap,
+    pub theme: Theme,
+    /// Name of the art pack in use, the art itself is held by `game_art`.
+    pub art_pack: String,
+    pub animations: bool,
+    /// Fills the free space of taken tiles with a pattern per mark, so they differ without color.
+    pub tile_patterns: bool,
+    /// Replaces art with plain lines and announces every move, for screen readers.
+    pub plain_text: bool,
+    /// Chosen in the settings screen, until then the language of the environment locale.
+    pub language: Language,
 }
 
 impl Settings{
     pub fn new() -> Settings{
         Settings{
-            player_color: Color::Blue,
-            opponent_color: Color::Red,
+            engine: None,
+            network_port: DEFAULT_PORT,
+            discovery_port: DISCOVERY_PORT,
+            data_dir: storage::default_data_dir(),
+            personality: Personality::classic(),
+            first_move: FirstMove::Player,
+            variant: Variant::Classic,
+            ai_think_time: AI_THINK_TIME,
+            time_control: TimeControl::UNLIMITED,
+            keymap: KeyMap::from_preset(KeyPreset::Arrows),
+            theme: Theme::classic(),
+            art_pack: DEFAULT_PACK.to_string(),
+            animations: true,
+            tile_patterns: false,
+            plain_text: false,
+            language: Language::from_environment(),
         }
     }
 
-    pub fn change_player_style(&mut self,color: Color){
-        self.player_color = color;
+    /// Reads the options changed in the settings screen that have no file of their own.
+    pub fn load_preferences(&mut self){
+        let rows = storage::read_rows(&self.data_dir.join(PREFERENCES_FILE)).unwrap_or_default();
+        for row in rows {
+            let (Some(key), Some(value)) = (row.first(), row.get(1)) else { continue };
+            match key.as_str() {
+                "animations" => self.animations = value != "off",
+                "tile_patterns" => self.tile_patterns = value == "on",
+                "plain_text" => self.plain_text = value == "on",
+                "language" => self.language = Language::from_code(value).unwrap_or(self.language),
+                "personality" => self.personality = Personality::find(&self.data_dir, value),
+                //An engine given on the command line wins over the saved one
+                "engine" if self.engine.is_none() => self.engine = EngineConfig::new(value),
+                "first_move" => self.first_move = FirstMove::from_str(value).unwrap_or(self.first_move),
+                "variant" => self.variant = Variant::from_str(value).unwrap_or(self.variant),
+                "ai_think_time" => {
+                    if let Ok(millis) = value.parse::<u64>() {
+                        self.ai_think_time = Duration::from_millis(millis).min(MAX_AI_THINK_TIME);
+                    }
+                }
+                _ => {}
+            }
+        }
     }
 
-    pub fn change_opponent_style(&mut self,color: Color){ self.opponent_color = color; }
+    pub fn save_preferences(&self) -> io::Result<()>{
+        let mut rows = vec![
+            vec!["animations".to_string(), if self.animations {"on"} else {"off"}.to_string()],
+            vec!["tile_patterns".to_string(), if self.tile_patterns {"on"} else {"off"}.to_string()],
+            vec!["plain_text".to_string(), if self.plain_text {"on"} else {"off"}.to_string()],
+            vec!["language".to_string(), self.language.code().to_string()],
+            vec!["personality".to_string(), storage::sanitize(&self.personality.name)],
+            vec!["first_move".to_string(), self.first_move.as_str().to_string()],
+            vec!["variant".to_string(), self.variant.as_str().to_string()],
+            vec!["ai_think_time".to_string(), self.ai_think_time.as_millis().to_string()],
+        ];
+        if let Some(engine) = &self.engine {
+            rows.push(vec!["engine".to_string(), storage::sanitize(&engine.command_line())]);
+        }
+        storage::write_rows(&self.data_dir.join(PREFERENCES_FILE), &rows)
+    }
+
+    /// Puts everything the settings screen changes back to its default and saves it,
+    /// the caller switches the art and language in use. Engine, ports and folders stay.
+    pub fn reset_to_defaults(&mut self) -> io::Result<()>{
+        let defaults = Settings::new();
+        self.personality = defaults.personality;
+        self.first_move = defaults.first_move;
+        self.variant = defaults.variant;
+        self.ai_think_time = defaults.ai_think_time;
+        self.keymap = defaults.keymap;
+        self.theme = defaults.theme;
+        self.art_pack = defaults.art_pack;
+        self.animations = defaults.animations;
+        self.tile_patterns = defaults.tile_patterns;
+        self.plain_text = defaults.plain_text;
+        self.language = defaults.language;
+
+        self.theme.save(&self.data_dir)?;
+        self.keymap.save(&self.data_dir)?;
+        ArtPack::built_in().save_selection(&self.data_dir)?;
+        self.save_preferences()
+    }
+
+    /// Switches to the next or previous personality, the files are read again every time so edited ones
+    /// show up without a restart. Returns the skipped files with the reason.
+    pub fn cycle_personality(&mut self, forward: bool) -> Vec<String>{
+        let (personalities, problems) = Personality::available(&self.data_dir);
+        let index = personalities.iter().position(|personality| personality.name == self.personality.name).unwrap_or(0);
+        let next = if forward {(index + 1) % personalities.len()} else {(index + personalities.len() - 1) % personalities.len()};
+        self.personality = personalities[next].clone();
+        problems
+    }
+
+    /// Changes the theme and saves it right away.
+    pub fn change_theme(&mut self, change: impl FnOnce(&mut Theme)) -> io::Result<()>{
+        change(&mut self.theme);
+        self.theme.save(&self.data_dir)
+    }
+
+    /// Changes the key bindings and saves them right away.
+    pub fn change_keymap(&mut self, change: impl FnOnce(&mut KeyMap)) -> io::Result<()>{
+        change(&mut self.keymap);
+        self.keymap.save(&self.data_dir)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,51 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Directory for profiles, records and other saved data, `TICTACTOE_HOME` overrides the default.
+pub fn default_data_dir() -> PathBuf{
+    if let Ok(dir) = std::env::var("TICTACTOE_HOME") {
+        return PathBuf::from(dir);
+    }
+    match std::env::var("HOME") {
+        Ok(home) => Path::new(&home).join(".tictactoe"),
+        Err(_) => PathBuf::from(".tictactoe"),
+    }
+}
+
+/// Reads a tab separated file, a missing file is treated as empty.
+pub fn read_rows(path: &Path) -> io::Result<Vec<Vec<String>>>{
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(error),
+    };
+
+    Ok(content.lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| line.split('\t').map(String::from).collect())
+        .collect())
+}
+
+pub fn write_rows(path: &Path, rows: &[Vec<String>]) -> io::Result<()>{
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let content: String = rows.iter().map(|row| row.join("\t") + "\n").collect();
+    fs::write(path, content)
+}
+
+pub fn append_row(path: &Path, row: &[String]) -> io::Result<()>{
+    use std::io::Write;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = fs::OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", row.join("\t"))
+}
+
+/// Tabs and line breaks would break the row format, so they never end up in saved text.
+pub fn sanitize(text: &str) -> String{
+    text.chars().map(|character| if character.is_control() {' '} else {character}).collect()
+}
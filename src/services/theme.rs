@@ -0,0 +1,256 @@
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use ratatui::style::{Color, Style};
+use crate::enums::theme_element::ThemeElement;
+use crate::services::storage;
+
+const THEME_FILE: &str = "theme.tsv";
+
+/// Colors for every part of the interface, `Color::Reset` keeps the terminal default.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme{
+    pub name: String,
+    pub player: Color,
+    pub opponent: Color,
+    pub text: Color,
+    pub background: Color,
+    pub border: Color,
+    pub highlight: Color,
+    pub accent: Color,
+    pub banner: Color,
+    pub win: Color,
+    pub loss: Color,
+}
+
+impl Theme{
+    pub fn classic() -> Theme{
+        Theme{
+            name: "Classic".to_string(),
+            player: Color::Blue,
+            opponent: Color::Red,
+            text: Color::Reset,
+            background: Color::Reset,
+            border: Color::Reset,
+            highlight: Color::Reset,
+            accent: Color::Magenta,
+            banner: Color::Reset,
+            win: Color::Green,
+            loss: Color::Red,
+        }
+    }
+
+    pub fn get_built_in() -> Vec<Theme>{
+        vec![
+            Theme::classic(),
+            Theme{
+                name: "Midnight".to_string(),
+                player: Color::Rgb(97,175,239),
+                opponent: Color::Rgb(224,108,117),
+                text: Color::Rgb(171,178,191),
+                background: Color::Rgb(30,33,39),
+                border: Color::Rgb(92,99,112),
+                highlight: Color::Rgb(62,68,81),
+                accent: Color::Rgb(198,120,221),
+                banner: Color::Rgb(229,192,123),
+                win: Color::Rgb(152,195,121),
+                loss: Color::Rgb(224,108,117),
+            },
+            Theme{
+                name: "Solarized".to_string(),
+                player: Color::Rgb(38,139,210),
+                opponent: Color::Rgb(203,75,22),
+                text: Color::Rgb(147,161,161),
+                background: Color::Rgb(0,43,54),
+                border: Color::Rgb(88,110,117),
+                highlight: Color::Rgb(7,54,66),
+                accent: Color::Rgb(108,113,196),
+                banner: Color::Rgb(181,137,0),
+                win: Color::Rgb(133,153,0),
+                loss: Color::Rgb(220,50,47),
+            },
+            Theme{
+                name: "Miku".to_string(),
+                player: Color::Rgb(134,206,203),
+                opponent: Color::Rgb(255,51,255),
+                text: Color::Rgb(230,230,230),
+                background: Color::Rgb(19,33,40),
+                border: Color::Rgb(134,206,203),
+                highlight: Color::Rgb(19,122,127),
+                accent: Color::Rgb(225,40,133),
+                banner: Color::Rgb(134,206,203),
+                win: Color::Rgb(134,206,203),
+                loss: Color::Rgb(225,40,133),
+            },
+            Theme{
+                name: "Paper".to_string(),
+                player: Color::Rgb(0,90,180),
+                opponent: Color::Rgb(180,30,30),
+                text: Color::Rgb(40,40,40),
+                background: Color::Rgb(245,242,232),
+                border: Color::Rgb(120,120,120),
+                highlight: Color::Rgb(215,210,190),
+                accent: Color::Rgb(130,60,160),
+                banner: Color::Rgb(40,40,40),
+                win: Color::Rgb(20,130,40),
+                loss: Color::Rgb(180,30,30),
+            },
+            Theme{
+                name: "High contrast".to_string(),
+                player: Color::Rgb(255,255,0),
+                opponent: Color::Rgb(0,255,255),
+                text: Color::Rgb(255,255,255),
+                background: Color::Rgb(0,0,0),
+                border: Color::Rgb(255,255,255),
+                highlight: Color::Reset,
+                accent: Color::Rgb(255,128,255),
+                banner: Color::Rgb(255,255,255),
+                win: Color::Rgb(0,255,0),
+                loss: Color::Rgb(255,96,96),
+            },
+            //Okabe-Ito colors, the pairs stay apart for red-green and blue-yellow color blindness
+            Theme{
+                name: "Colorblind".to_string(),
+                player: Color::Rgb(0,114,178),
+                opponent: Color::Rgb(230,159,0),
+                text: Color::Reset,
+                background: Color::Reset,
+                border: Color::Reset,
+                highlight: Color::Reset,
+                accent: Color::Rgb(204,121,167),
+                banner: Color::Reset,
+                win: Color::Rgb(0,158,115),
+                loss: Color::Rgb(213,94,0),
+            },
+        ]
+    }
+
+    /// Next built in theme, a customized theme continues with the first one.
+    pub fn next_built_in(&self) -> Theme{
+        let themes = Theme::get_built_in();
+        let index = themes.iter().position(|theme| theme.name == self.name).map(|index| index + 1).unwrap_or(0);
+        themes[index % themes.len()].clone()
+    }
+
+    pub fn previous_built_in(&self) -> Theme{
+        let themes = Theme::get_built_in();
+        let index = themes.iter().position(|theme| theme.name == self.name).unwrap_or(0);
+        themes[(index + themes.len() - 1) % themes.len()].clone()
+    }
+
+    pub fn color(&self, element: ThemeElement) -> Color{
+        match element {
+            ThemeElement::Player => self.player,
+            ThemeElement::Opponent => self.opponent,
+            ThemeElement::Text => self.text,
+            ThemeElement::Background => self.background,
+            ThemeElement::Border => self.border,
+            ThemeElement::Highlight => self.highlight,
+            ThemeElement::Accent => self.accent,
+            ThemeElement::Banner => self.banner,
+            ThemeElement::Win => self.win,
+            ThemeElement::Loss => self.loss,
+        }
+    }
+
+    /// Changes one color, the theme is no longer one of the built in ones afterwards.
+    pub fn set_color(&mut self, element: ThemeElement, color: Color){
+        let target = match element {
+            ThemeElement::Player => &mut self.player,
+            ThemeElement::Opponent => &mut self.opponent,
+            ThemeElement::Text => &mut self.text,
+            ThemeElement::Background => &mut self.background,
+            ThemeElement::Border => &mut self.border,
+            ThemeElement::Highlight => &mut self.highlight,
+            ThemeElement::Accent => &mut self.accent,
+            ThemeElement::Banner => &mut self.banner,
+            ThemeElement::Win => &mut self.win,
+            ThemeElement::Loss => &mut self.loss,
+        };
+        *target = color;
+        self.name = "Custom".to_string();
+    }
+
+    /// Text and background for a whole screen.
+    pub fn base_style(&self) -> Style{
+        Style::new().fg(self.text).bg(self.background)
+    }
+
+    pub fn border_style(&self) -> Style{
+        Style::new().fg(self.border)
+    }
+
+    /// Selected list entries, without a highlight color the entry is drawn reversed.
+    pub fn highlight_style(&self) -> Style{
+        if self.highlight == Color::Reset {
+            Style::new().reversed()
+        }else{
+            Style::new().bg(self.highlight)
+        }
+    }
+
+    pub fn accent_style(&self) -> Style{
+        Style::new().fg(self.accent)
+    }
+
+    pub fn banner_style(&self) -> Style{
+        Style::new().fg(self.banner)
+    }
+
+    pub fn player_style(&self) -> Style{
+        Style::new().fg(self.player)
+    }
+
+    pub fn opponent_style(&self) -> Style{
+        Style::new().fg(self.opponent)
+    }
+
+    pub fn win_style(&self) -> Style{
+        Style::new().fg(self.win)
+    }
+
+    pub fn loss_style(&self) -> Style{
+        Style::new().fg(self.loss)
+    }
+
+    /// Loads the saved theme, missing colors are taken from the classic theme.
+    pub fn load(data_dir: &Path) -> Theme{
+        let rows = storage::read_rows(&data_dir.join(THEME_FILE)).unwrap_or_default();
+        let mut theme = Theme::classic();
+        for row in rows {
+            let (Some(key), Some(value)) = (row.first(), row.get(1)) else { continue };
+            if key == "name" {
+                theme.name = value.clone();
+            }else if let (Some(element), Ok(color)) = (ThemeElement::from_str(key), Color::from_str(value)) {
+                let name = theme.name.clone();
+                theme.set_color(element, color);
+                theme.name = name;
+            }
+        }
+        theme
+    }
+
+    pub fn save(&self, data_dir: &Path) -> io::Result<()>{
+        let mut rows = vec![vec!["name".to_string(), storage::sanitize(&self.name)]];
+        rows.extend(ThemeElement::get_all().into_iter().map(|element| vec![element.as_str().to_string(), self.color(element).to_string()]));
+        storage::write_rows(&data_dir.join(THEME_FILE), &rows)
+    }
+}
+
+/// Parses `#rrggbb`, `rrggbb`, `r,g,b`, `rgb(r,g,b)` or a color name like `blue`.
+pub fn parse_color(text: &str) -> Option<Color>{
+    let text = text.trim();
+    let numbers = text.strip_prefix("rgb(").and_then(|rest| rest.strip_suffix(')')).unwrap_or(text);
+    let parts: Vec<&str> = numbers.split(',').map(str::trim).collect();
+    if parts.len() == 3 {
+        let channels: Vec<u8> = parts.iter().filter_map(|part| part.parse().ok()).collect();
+        return if channels.len() == 3 {Some(Color::Rgb(channels[0], channels[1], channels[2]))} else {None};
+    }
+
+    let hex = text.strip_prefix('#').unwrap_or(text);
+    if hex.len() == 6 && hex.chars().all(|character| character.is_ascii_hexdigit()) {
+        let channel = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).ok();
+        return Some(Color::Rgb(channel(0)?, channel(2)?, channel(4)?));
+    }
+    Color::from_str(text).ok()
+}
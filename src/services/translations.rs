@@ -0,0 +1,329 @@
+use crate::enums::language::Language;
+
+/// Translations keyed by the English text in the code, English itself needs no catalog.
+pub fn catalog(language: Language) -> &'static [(&'static str, &'static str)]{
+    match language {
+        Language::English => &[],
+        Language::Polish => POLISH,
+    }
+}
+
+//Placeholders `{}` are filled in order, so a translation keeps them in the same order
+//Text that reads the same in Polish is listed too, src/tests/translations.rs fails on text missing here
+const POLISH: &[(&str, &str)] = &[
+    //Main
+    ("Could not remove saved game: {}", "Nie udało się usunąć zapisanej gry: {}"),
+
+    //Settings view
+    ("Language", "Język"),
+    ("Theme", "Motyw"),
+    ("Edit colors", "Edytuj kolory"),
+    ("Art pack", "Zestaw grafik"),
+    ("Animations", "Animacje"),
+    ("Key preset", "Układ klawiszy"),
+    ("Rebind keys", "Zmień klawisze"),
+    ("Go back", "Wróć"),
+    ("On", "Wł."),
+    ("Off", "Wył."),
+    ("Could not save theme: {}", "Nie udało się zapisać motywu: {}"),
+    ("Could not read art pack {}: {}", "Nie udało się wczytać zestawu grafik {}: {}"),
+    ("Could not save art pack: {}", "Nie udało się zapisać zestawu grafik: {}"),
+    ("Skipped art file {}", "Pominięto plik grafiki {}"),
+    ("Could not save settings: {}", "Nie udało się zapisać ustawień: {}"),
+    ("Could not save key bindings: {}", "Nie udało się zapisać klawiszy: {}"),
+    ("Change value", "Zmień wartość"),
+    ("Open or change", "Otwórz lub zmień"),
+    ("Back to menu", "Powrót do menu"),
+    ("Select", "Wybierz"),
+
+    //Key tables
+    ("Answer", "Odpowiedz"),
+    ("Apply color", "Ustaw kolor"),
+    ("Back", "Wróć"),
+    ("Cancel", "Anuluj"),
+    ("Change color", "Zmień kolor"),
+    ("Choose", "Wybierz"),
+    ("Close", "Zamknij"),
+    ("Create profile", "Utwórz profil"),
+    ("Join", "Dołącz"),
+    ("No", "Nie"),
+    ("Open", "Otwórz"),
+    ("Pause menu", "Menu pauzy"),
+    ("Place mark", "Postaw znak"),
+    ("Place on tile", "Postaw na polu"),
+    ("Quit", "Wyjdź"),
+    ("Rebind", "Zmień klawisz"),
+    ("Resume", "Wznów"),
+    ("Scroll", "Przewiń"),
+    ("Select game", "Wybierz grę"),
+    ("Select profile", "Wybierz profil"),
+    ("Select tile", "Wybierz pole"),
+    ("Show keys", "Pokaż klawisze"),
+    ("Spectate", "Oglądaj"),
+    ("Type address", "Wpisz adres"),
+    ("Yes", "Tak"),
+
+    //Notifications
+    ("{} - messages ({})", "{} - wiadomości ({})"),
+    ("Messages", "Wiadomości"),
+    ("{},{} - scroll, {} - close", "{},{} - przewijanie, {} - zamknij"),
+    ("No messages yet", "Brak wiadomości"),
+    ("{}s ago", "{} s temu"),
+    ("Info", "Informacja"),
+    ("Done", "Gotowe"),
+    ("Error", "Błąd"),
+
+    //Main view
+    ("Continue saved game", "Kontynuuj zapisaną grę"),
+    ("Start Game with Ai", "Graj z komputerem"),
+    ("Start Local Co-op game", "Graj we dwoje na jednym komputerze"),
+    ("Start Game with external Engine", "Graj z zewnętrznym silnikiem"),
+    ("Host network game", "Załóż grę sieciową"),
+    ("Join network game", "Dołącz do gry sieciowej"),
+    ("Statistics", "Statystyki"),
+    ("Settings", "Ustawienia"),
+    ("Your current terminal size is {},{} the game needs at least {},{}", "Terminal ma rozmiar {},{}, gra potrzebuje co najmniej {},{}"),
+    ("TIC TAC TOE", "KÓŁKO I KRZYŻYK"),
+
+    //Seats view
+    ("Cross (X)", "Krzyżyk (X)"),
+    ("Circle (O)", "Kółko (O)"),
+    ("Time control: < {} >", "Czas gry: < {} >"),
+    ("Create new profile", "Utwórz nowy profil"),
+    ("Start game", "Rozpocznij grę"),
+    ("{},{} - change selection, {} - confirm, {} - go back", "{},{} - zmiana wyboru, {} - zatwierdź, {} - wróć"),
+
+    //Ai levels
+    ("Easy", "Łatwy"),
+    ("Normal", "Normalny"),
+    ("Hard", "Trudny"),
+
+    //Statistics view
+    ("Could not read saved games: {}", "Nie udało się wczytać rozegranych gier: {}"),
+    ("Create a profile to track ratings", "Utwórz profil, aby śledzić ranking"),
+    ("Rating history", "Historia rankingu"),
+    ("Rating history of {}: {} - {}, {} rated games", "Historia rankingu: {}, {} - {}, gry rankingowe: {}"),
+    ("Profile", "Profil"),
+    ("Rating", "Ranking"),
+    ("Games", "Gry"),
+    ("Wins", "Wygrane"),
+    ("Losses", "Przegrane"),
+    ("Draws", "Remisy"),
+    ("Profiles", "Profile"),
+    ("AI level", "Poziom komputera"),
+    ("AI levels", "Poziomy komputera"),
+    ("{},{} - choose profile, {} - go back", "{},{} - wybór profilu, {} - wróć"),
+
+    //Lobby view
+    ("open", "wolna"),
+    ("full", "pełna"),
+    ("Searching for games on the local network...", "Szukanie gier w sieci lokalnej..."),
+    ("{} - join game, S - spectate, A - type address, {} - go back", "{} - dołącz, S - oglądaj, A - wpisz adres, {} - wróć"),
+
+    //Join view
+    ("Invalid address: {}", "Nieprawidłowy adres: {}"),
+    ("Host address", "Adres gospodarza"),
+    ("Enter - join game, ESC - go back", "Enter - dołącz, ESC - wróć"),
+
+    //New profile view
+    ("Profile name", "Nazwa profilu"),
+    ("Enter - create profile, ESC - go back", "Enter - utwórz profil, ESC - wróć"),
+
+    //Time controls
+    ("Unlimited", "Bez limitu"),
+    ("{} min", "{} min"),
+    ("{} s", "{} s"),
+    ("{} + {} s", "{} + {} s"),
+    ("{} s per move", "{} s na ruch"),
+
+    //Overlays
+    ("Paused", "Pauza"),
+    ("{} - select, {} - resume", "{} - wybierz, {} - wznów"),
+    ("Are you sure?", "Na pewno?"),
+    ("{} - answer, Y/N, {} - no", "{} - odpowiedz, Y/N, {} - nie"),
+    ("Unknown color {}, use #rrggbb, r,g,b or a color name", "Nieznany kolor {}, użyj #rrggbb, r,g,b lub nazwy koloru"),
+    ("Select {} color", "Kolor: {}"),
+    ("Custom (hex or r,g,b)", "Własny (hex lub r,g,b)"),
+    ("#rrggbb, r,g,b or name", "#rrggbb, r,g,b lub nazwa"),
+    ("Enter - apply, ESC - cancel", "Enter - ustaw, ESC - anuluj"),
+    ("{} - apply, {} - back", "{} - ustaw, {} - wróć"),
+    ("Colors of {}", "Kolory motywu {}"),
+    ("{} - change color, {} - close", "{} - zmień kolor, {} - zamknij"),
+    ("{}: press a key...", "{}: naciśnij klawisz..."),
+    ("{} - rebind, {} - close", "{} - zmień klawisz, {} - zamknij"),
+    ("Keys", "Klawisze"),
+    ("{} - close", "{} - zamknij"),
+
+    //Theme elements
+    ("Player", "Gracz"),
+    ("Opponent", "Przeciwnik"),
+    ("Text", "Tekst"),
+    ("Background", "Tło"),
+    ("Borders", "Ramki"),
+    ("Highlight", "Zaznaczenie"),
+    ("Accent", "Akcent"),
+    ("Banners", "Napisy"),
+    ("Win", "Wygrana"),
+    ("Loss", "Przegrana"),
+
+    //Key actions
+    ("Move up", "W górę"),
+    ("Move down", "W dół"),
+    ("Move left", "W lewo"),
+    ("Move right", "W prawo"),
+    ("Confirm", "Zatwierdź"),
+    ("Message log", "Dziennik wiadomości"),
+    ("Help", "Pomoc"),
+    ("Tile {}", "Pole {}"),
+    ("Arrows", "Strzałki"),
+    ("Numpad (1-9 tiles)", "Klawiatura numeryczna (pola 1-9)"),
+    ("Custom", "Własny"),
+
+    //Pause menu
+    ("Leave the game? It is not finished yet.", "Opuścić grę? Nie jest jeszcze skończona."),
+    ("Restart the game? The current one is not finished yet.", "Zacząć od nowa? Obecna gra nie jest jeszcze skończona."),
+    ("Restart", "Zacznij od nowa"),
+    ("Save", "Zapisz"),
+    ("Quit to menu", "Wyjdź do menu"),
+
+    //Game view
+    ("Saved game is broken: {}", "Zapisana gra jest uszkodzona: {}"),
+    ("Game saved, continue it from the main menu", "Gra zapisana, możesz ją kontynuować z menu głównego"),
+    ("Could not save game: {}", "Nie udało się zapisać gry: {}"),
+    ("Game saved", "Gra zapisana"),
+    ("Controls", "Sterowanie"),
+    ("Game", "Gra"),
+    ("thinking {}%", "myślę {}%"),
+    ("waiting for turn..", "czekam na kolej.."),
+    ("calculating move", "obliczam ruch"),
+    ("Yay i won, you suck", "Hura, wygrałem, jesteś słaby"),
+    ("You are as bad as me", "Jesteś tak samo słaby jak ja"),
+    ("I will remember that", "Zapamiętam to sobie"),
+    ("OFFLINE", "ROZŁĄCZONO"),
+    ("waiting for player to join on port {}", "czekam na gracza na porcie {}"),
+    ("game over", "koniec gry"),
+    ("spectating", "oglądasz"),
+    ("waiting for remote player", "czekam na ruch rywala"),
+    ("your move", "twój ruch"),
+    ("Engine: {}", "Silnik: {}"),
+    ("Remote: {}", "Rywal: {}"),
+    ("Hosting", "Gospodarz"),
+    ("Host: {}", "Gospodarz: {}"),
+    ("Engine", "Silnik"),
+    ("Remote", "Rywal sieciowy"),
+    ("AI Status", "Stan komputera"),
+    ("{} (X) vs {} (O)", "{} (X) kontra {} (O)"),
+    ("Draw", "Remis"),
+    ("{} ran out of time, {} won!", "{}: koniec czasu, wygrywa {}!"),
+    ("{} won!", "Wygrywa {}!"),
+    ("move: {}", "ruch: {}"),
+    ("The game is full, you are spectating", "Gra jest pełna, oglądasz ją jako widz"),
+    ("Spectators: {}", "Widzowie: {}"),
+    ("Field number out of scope", "Numer pola poza planszą"),
+    ("Select empty field", "Wybierz puste pole"),
+    ("Not your turn", "To nie twoja kolej"),
+    ("Spectators cannot move", "Widzowie nie mogą wykonywać ruchów"),
+
+    //Banners
+    ("Your turn", "Twoja tura"),
+    ("Enemy turn", "Tura rywala"),
+    ("Cross turn", "Tura krzyżyka"),
+    ("Circle turn", "Tura kółka"),
+    ("You won", "Wygrana"),
+    ("You lost", "Przegrana"),
+    ("Cross won", "Krzyżyk wygrał"),
+    ("Circle won", "Kółko wygrało"),
+
+    //Profiles
+    ("Guest", "Gość"),
+    ("Name cannot be empty", "Nazwa nie może być pusta"),
+    ("Name can have at most {} characters", "Nazwa może mieć najwyżej {} znaków"),
+    ("Profile {} already exists", "Profil {} już istnieje"),
+    ("Could not save profiles: {}", "Nie udało się zapisać profili: {}"),
+
+    //Engines and network
+    ("No engine configured, enter its command in Settings or start the game with --engine <command>", "Nie wybrano silnika, wpisz jego polecenie w Ustawieniach lub uruchom grę z --engine <polecenie>"),
+    ("Could not start engine: {}", "Nie udało się uruchomić silnika: {}"),
+    ("Engine communication failed: {}", "Błąd komunikacji z silnikiem: {}"),
+    ("Engine did not answer in time ({})", "Silnik nie odpowiedział na czas ({})"),
+    ("Engine process exited", "Proces silnika się zakończył"),
+    ("Engine sent unexpected line: {}", "Silnik wysłał nieoczekiwaną linię: {}"),
+    ("Engine played illegal move {}", "Silnik wykonał niedozwolony ruch {}"),
+    ("Network error: {}", "Błąd sieci: {}"),
+    ("Remote player disconnected", "Rywal się rozłączył"),
+    ("Remote side sent unexpected line: {}", "Druga strona wysłała nieoczekiwaną linię: {}"),
+    ("Host rejected the connection: {}", "Gospodarz odrzucił połączenie: {}"),
+
+    //Art packs
+    ("unknown art file", "nieznany plik grafiki"),
+    ("file is empty", "plik jest pusty"),
+    ("tabs and control characters are not allowed", "tabulatory i znaki sterujące są niedozwolone"),
+    ("art is {}x{}, at most {}x{} fits", "grafika ma {}x{}, mieści się najwyżej {}x{}"),
+
+    //Accessibility
+    ("Tile patterns", "Wzory na polach"),
+    ("Plain text mode", "Tryb czystego tekstu"),
+    ("Players", "Gracze"),
+    ("New game", "Nowa gra"),
+    ("{} on {} ({})", "{} na polu {} ({})"),
+    ("top left", "lewym górnym"),
+    ("top", "górnym"),
+    ("top right", "prawym górnym"),
+    ("left", "lewym"),
+    ("center", "środkowym"),
+    ("right", "prawym"),
+    ("bottom left", "lewym dolnym"),
+    ("bottom", "dolnym"),
+    ("bottom right", "prawym dolnym"),
+
+    //Personalities
+    ("AI: < {} - {} ({}) >", "Komputer: < {} - {} ({}) >"),
+    ("Skipped personality file {}", "Pominięto plik osobowości {}"),
+    ("{}: the name {} is taken", "{}: nazwa {} jest już zajęta"),
+    ("unknown AI level {}", "nieznany poziom komputera {}"),
+    ("art pack {} not found", "nie znaleziono zestawu grafik {}"),
+    ("unknown row {}", "nieznany wiersz {}"),
+    ("Not so fast", "Nie tak szybko"),
+    ("Are you sure about that?", "Na pewno?"),
+    ("Take your time", "Nie spiesz się"),
+    ("I am still learning", "Dopiero się uczę"),
+    ("Eeny, meeny, miny...", "Ene, due, rike..."),
+    ("Hmm, this one?", "Hmm, może to?"),
+    ("Oh, I saw that one!", "O, to widziałem!"),
+    ("Was that a trick?", "To była jakaś sztuczka?"),
+    ("I won? I won!", "Wygrałem? Wygrałem!"),
+    ("Well played, teach me that one", "Dobrze zagrane, naucz mnie tego"),
+    ("Nobody lost, nice", "Nikt nie przegrał, fajnie"),
+    ("Take all the time you need", "Masz tyle czasu, ile potrzebujesz"),
+    ("Calculating every line", "Liczę każdy wariant"),
+    ("Searching the tree", "Przeszukuję drzewo"),
+    ("Predictable", "Przewidywalne"),
+    ("A mistake, and you know it", "Błąd, i dobrze o tym wiesz"),
+    ("As expected", "Zgodnie z planem"),
+    ("Impossible...", "Niemożliwe..."),
+    ("The only correct result", "Jedyny poprawny wynik"),
+
+    //Gameplay settings
+    ("Gameplay", "Rozgrywka"),
+    ("Display", "Wygląd"),
+    ("AI opponent", "Przeciwnik AI"),
+    ("First move", "Pierwszy ruch"),
+    ("Rules", "Zasady"),
+    ("AI think delay", "Czas namysłu AI"),
+    ("Reset to defaults", "Przywróć domyślne"),
+    ("Classic", "Klasyczne"),
+    ("Misère", "Odwrotne"),
+    ("You", "Ty"),
+    ("Random", "Losowo"),
+    ("Reset all settings to their defaults?", "Przywrócić wszystkie ustawienia domyślne?"),
+    ("Settings reset to defaults", "Przywrócono ustawienia domyślne"),
+
+    //Engine settings
+    ("Engine command", "Polecenie silnika"),
+    ("not set", "nie ustawiono"),
+    ("Save engine command", "Zapisz polecenie silnika"),
+    ("Engine command, e.g. python3 engines/random_engine.py", "Polecenie silnika, np. python3 engines/random_engine.py"),
+    ("Engine: {} (change in Settings)", "Silnik: {} (zmiana w Ustawieniach)"),
+    ("Engine: not set, choose one in Settings", "Silnik: nie ustawiono, wybierz go w Ustawieniach"),
+    ("connecting to engine...", "łączenie z silnikiem..."),
+];
@@ -0,0 +1,17 @@
+use std::time::{Duration, Instant};
+use crate::enums::field::FieldMark;
+use crate::services::clock::{GameClock, TimeControl};
+
+//A turn that changes during a pause starts when the game is resumed, not after the paused time
+#[test]
+fn turn_switched_while_paused_gets_the_full_move_time(){
+    let control = TimeControl{ total: None, per_move: Some(Duration::from_secs(10)), increment: Duration::ZERO };
+    let start = Instant::now();
+    let mut clock = GameClock::new(control, start);
+
+    clock.pause(start + Duration::from_secs(2));
+    clock.switch_turn(FieldMark::O, start + Duration::from_secs(5));
+    clock.resume(start + Duration::from_secs(8));
+    assert_eq!(clock.move_remaining(start + Duration::from_secs(8)), Some(Duration::from_secs(10)));
+    assert_eq!(clock.move_remaining(start + Duration::from_secs(11)), Some(Duration::from_secs(7)));
+}
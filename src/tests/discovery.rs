@@ -0,0 +1,60 @@
+use std::net::{Ipv4Addr, SocketAddr, UdpSocket};
+use std::time::{Duration, Instant};
+use crate::services::discovery::{DiscoveryClient, DiscoveryResponder};
+
+/// Port nobody listens on right now, so tests running at the same time do not answer each other.
+fn free_udp_port() -> u16{
+    let socket = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+    socket.local_addr().unwrap().port()
+}
+
+/// Updates the lobby until `done` holds for its games, `answer` plays the host side in between.
+fn update_until(client: &mut DiscoveryClient, mut answer: impl FnMut(), done: impl Fn(&DiscoveryClient) -> bool){
+    let started = Instant::now();
+    while !done(client) {
+        answer();
+        client.update().unwrap();
+        assert!(started.elapsed() < Duration::from_secs(5), "the lobby did not get there in time");
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn lobby_finds_game_on_loopback(){
+    let discovery_port = free_udp_port();
+    let responder = DiscoveryResponder::bind(discovery_port, 7000, "test-host".to_string()).unwrap();
+    let mut client = DiscoveryClient::new(discovery_port).unwrap();
+
+    update_until(&mut client, || responder.answer_queries(true), |client| !client.games().is_empty());
+    let game = &client.games()[0];
+    assert_eq!(game.address, SocketAddr::from((Ipv4Addr::LOCALHOST, 7000)));
+    assert_eq!(game.host_name, "test-host");
+    assert!(game.open);
+
+    //Later answers update the listed game instead of adding another one
+    update_until(&mut client, || responder.answer_queries(false), |client| !client.games()[0].open);
+    assert_eq!(client.games().len(), 1);
+}
+
+//127.0.0.2 stands in for the network address of this machine, both answers are the same game
+#[cfg(target_os = "linux")]
+#[test]
+fn local_game_is_listed_once(){
+    let lobby = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let mut client = DiscoveryClient::new(lobby.local_addr().unwrap().port()).unwrap();
+    client.update().unwrap();
+    lobby.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    let (_, client_address) = lobby.recv_from(&mut [0u8; 512]).unwrap();
+
+    let network_side = UdpSocket::bind((Ipv4Addr::new(127, 0, 0, 2), 0)).unwrap();
+    let answer = "tictactoe-game 1 7000 classic open test-host";
+    network_side.send_to(answer.as_bytes(), client_address).unwrap();
+    update_until(&mut client, || {}, |client| !client.games().is_empty());
+    lobby.send_to(answer.as_bytes(), client_address).unwrap();
+
+    //A second game on another port of the same machine is listed next to it
+    lobby.send_to("tictactoe-game 1 7001 classic open test-host".as_bytes(), client_address).unwrap();
+    update_until(&mut client, || {}, |client| client.games().len() == 2);
+    assert_eq!(client.games()[0].address, SocketAddr::from((Ipv4Addr::LOCALHOST, 7000)));
+    assert_eq!(client.games()[1].address, SocketAddr::from((Ipv4Addr::LOCALHOST, 7001)));
+}
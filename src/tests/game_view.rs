@@ -0,0 +1,234 @@
+use ratatui::crossterm::event::KeyCode;
+use std::time::{Duration, Instant};
+use crate::enums::first_move::FirstMove;
+use crate::enums::player::Player;
+use crate::enums::player_type::PlayerType;
+use crate::enums::view_action::ViewAction;
+use crate::services::keymap::{KeyMap, KeyPreset};
+use crate::services::clock::TimeControl;
+use crate::enums::variant::Variant;
+use crate::services::rating::INITIAL_RATING;
+use crate::services::records;
+use crate::services::engine::EngineConfig;
+use crate::tests::harness::TestApp;
+
+//The seats screen has four rows above "Start game" for both game types
+fn start_game(app: &mut TestApp, menu_index: usize){
+    app.press_keys(&vec![KeyCode::Down; menu_index]);
+    app.press(KeyCode::Enter);
+    app.press_keys(&[KeyCode::Down; 4]);
+    app.press(KeyCode::Enter);
+}
+
+/// Board of the game on top as `x`, `o` and `.` from left to right, top to bottom.
+fn board(app: &mut TestApp) -> String{
+    let state = app.describe_state();
+    state.lines().find_map(|line| line.strip_prefix("board: ")).expect("the game view describes its board").to_string()
+}
+
+fn start_ai_game(app: &mut TestApp){
+    start_game(app, 0);
+}
+
+fn start_local_game(app: &mut TestApp){
+    start_game(app, 1);
+}
+
+fn start_engine_game(app: &mut TestApp){
+    start_game(app, 2);
+}
+
+#[test]
+fn ai_game_start(){
+    let mut app = TestApp::new("game-ai-start");
+    start_ai_game(&mut app);
+    app.assert_snapshot("game_ai_start", 100, 36);
+}
+
+#[test]
+fn ai_game_compact(){
+    let mut app = TestApp::new("game-ai-compact");
+    start_ai_game(&mut app);
+    app.assert_snapshot("game_ai_compact", 40, 20);
+}
+
+#[test]
+fn placing_a_mark(){
+    let mut app = TestApp::new("game-place");
+    start_ai_game(&mut app);
+    app.press_keys(&[KeyCode::Up, KeyCode::Enter]);
+    assert_eq!(board(&mut app), "....x....");
+    app.assert_snapshot("game_ai_after_move", 100, 36);
+}
+
+#[test]
+fn ai_answers_a_move(){
+    let mut app = TestApp::new("game-ai-answer");
+    start_ai_game(&mut app);
+    app.press(KeyCode::Enter);
+    app.tick_until(|state| state.current_view().describe_state().contains("to move: X"));
+    assert_eq!(board(&mut app).matches('o').count(), 1);
+}
+
+#[test]
+fn taken_tile_is_refused(){
+    let mut app = TestApp::new("game-taken");
+    start_local_game(&mut app);
+    app.press_keys(&[KeyCode::Enter, KeyCode::Enter]);
+    assert!(app.describe_state().contains("to move: O"));
+    assert!(app.screen(100, 36).contains("Select empty field"));
+}
+
+#[test]
+fn local_game_win(){
+    let mut app = TestApp::new("game-local-win");
+    start_local_game(&mut app);
+    //Cross takes the bottom row, circle the middle one
+    app.press_keys(&[KeyCode::Left, KeyCode::Enter, KeyCode::Up, KeyCode::Enter, KeyCode::Down, KeyCode::Right, KeyCode::Enter]);
+    app.press_keys(&[KeyCode::Up, KeyCode::Enter, KeyCode::Down, KeyCode::Right, KeyCode::Enter]);
+    assert!(app.describe_state().contains("winner: Some(X)"));
+    app.assert_snapshot("game_local_win", 100, 36);
+
+    //Finished games are recorded on the next tick
+    app.tick_until(|_| true);
+    let records = records::load_all(app.data_dir()).unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].mode, PlayerType::Human);
+    assert_eq!(records[0].moves, vec![6, 3, 7, 4, 8]);
+}
+
+#[test]
+fn misere_line_loses(){
+    let mut app = TestApp::with_settings("game-misere", |settings| settings.variant = Variant::Misere);
+    start_local_game(&mut app);
+    app.press_keys(&[KeyCode::Left, KeyCode::Enter, KeyCode::Up, KeyCode::Enter, KeyCode::Down, KeyCode::Right, KeyCode::Enter]);
+    app.press_keys(&[KeyCode::Up, KeyCode::Enter, KeyCode::Down, KeyCode::Right, KeyCode::Enter]);
+    assert!(app.describe_state().contains("winner: Some(O)"));
+}
+
+#[test]
+fn clicking_a_tile_places_a_mark(){
+    let mut app = TestApp::new("game-click");
+    start_local_game(&mut app);
+    app.render(100, 36);
+    app.click(25, 19);
+    assert_eq!(board(&mut app), "x........");
+}
+
+#[test]
+fn plain_text_game(){
+    let mut app = TestApp::with_settings("game-plain", |settings| settings.plain_text = true);
+    start_ai_game(&mut app);
+    app.press(KeyCode::Enter);
+    app.assert_snapshot("game_plain_text", 80, 24);
+}
+
+#[test]
+fn escape_opens_the_pause_menu(){
+    let mut app = TestApp::new("game-pause");
+    start_ai_game(&mut app);
+    app.press(KeyCode::Esc);
+    app.assert_snapshot("game_pause_menu", 80, 24);
+}
+
+//`sleep` never answers the handshake, the game has to stay usable meanwhile
+#[cfg(unix)]
+#[test]
+fn slow_engine_does_not_block(){
+    let mut app = TestApp::with_settings("game-engine-slow", |settings| settings.engine = EngineConfig::new("sleep 10"));
+    let started = Instant::now();
+    start_engine_game(&mut app);
+    assert!(started.elapsed() < Duration::from_secs(1));
+    assert!(app.screen(100, 36).contains("connecting to"));
+
+    app.press(KeyCode::Enter);
+    assert_eq!(board(&mut app), ".......x.");
+}
+
+#[test]
+fn engine_game_without_engine_points_to_settings(){
+    let mut app = TestApp::new("game-engine-none");
+    app.press_keys(&[KeyCode::Down, KeyCode::Down, KeyCode::Enter]);
+    assert!(app.screen(100, 36).contains("Engine: not set, choose one in Settings"));
+
+    app.press_keys(&[KeyCode::Down, KeyCode::Enter]);
+    assert_eq!(app.state.current_view().name(), "SettingsView");
+}
+
+//The AI finishes its search behind the pause menu, its move is played only after resuming
+#[test]
+fn ai_waits_behind_the_pause_menu(){
+    let mut app = TestApp::with_settings("game-paused-ai", |settings| settings.ai_think_time = Duration::from_millis(100));
+    start_ai_game(&mut app);
+    app.press(KeyCode::Enter);
+    app.tick();
+    app.press(KeyCode::Esc);
+    assert_eq!(app.state.current_view().name(), "PauseView");
+
+    let paused_at = Instant::now();
+    while paused_at.elapsed() < Duration::from_millis(300) {
+        app.tick();
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    //Main menu, seats, game and the pause menu on top
+    assert!(app.state.views[2].describe_state().contains("moves: [7]"));
+
+    app.press(KeyCode::Esc);
+    assert_eq!(app.state.current_view().name(), "AiGameView");
+    app.tick_until(|state| state.current_view().describe_state().contains("moves: [7, "));
+}
+
+//Ticks only draw what moves by itself, a game waiting for a key is left alone
+#[test]
+fn waiting_game_is_not_redrawn(){
+    let mut app = TestApp::new("game-idle");
+    start_ai_game(&mut app);
+    app.press(KeyCode::Enter);
+    app.tick_until(|state| state.current_view().describe_state().contains("moves: [7, "));
+    assert!(!app.tick());
+}
+
+#[test]
+fn running_clock_is_redrawn(){
+    let mut app = TestApp::with_settings("game-clock", |settings| settings.time_control = TimeControl::presets()[1]);
+    start_local_game(&mut app);
+    assert!(app.tick());
+    assert!(app.tick());
+}
+
+//The AI moves first, so the profile plays circle and its rating is the second one of the record
+#[test]
+fn rating_history_when_the_ai_starts(){
+    let mut app = TestApp::with_settings("game-ai-first", |settings| settings.first_move = FirstMove::Opponent);
+    app.state.settings.borrow_mut().keymap = KeyMap::from_preset(KeyPreset::Numpad);
+    {
+        let mut profiles = app.state.profiles.borrow_mut();
+        let index = profiles.add("Alice").unwrap();
+        profiles.set_seat(Player::Player, Some(index));
+    }
+    start_ai_game(&mut app);
+
+    //Numpad keys place a mark on the tile directly, the first empty tile is taken each turn
+    loop {
+        app.tick_until(|state| {
+            let description = state.current_view().describe_state();
+            description.contains("to move: O") || !description.contains("winner: None")
+        });
+        if !app.describe_state().contains("winner: None") {
+            break;
+        }
+        let index = board(&mut app).find('.').unwrap();
+        app.press(KeyCode::Char("789456123".chars().nth(index).unwrap()));
+    }
+    app.tick();
+
+    let rating = app.state.profiles.borrow().profiles[0].rating;
+    let records = records::load_all(app.data_dir()).unwrap();
+    assert_eq!(records[0].circle_name, "Alice");
+    assert_eq!(records[0].rating_of("Alice"), Some(rating));
+
+    app.state.handle_view_action(ViewAction::GoToStatistics);
+    let (lowest, highest) = (rating.min(INITIAL_RATING), rating.max(INITIAL_RATING));
+    let title = format!("Rating history of Alice: {:.0} - {:.0}, 1 rated games", lowest, highest);
+    assert!(app.screen(100, 36).contains(&title));
+}
@@ -0,0 +1,148 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::Terminal;
+use crate::AppState;
+use crate::enums::app_event::AppEvent;
+use crate::enums::language::Language;
+use crate::services::settings::Settings;
+
+/// Set to rewrite the stored snapshots with what the views draw now, e.g. `UPDATE_SNAPSHOTS=1 cargo test`.
+const UPDATE_VARIABLE: &str = "UPDATE_SNAPSHOTS";
+
+/// The whole app with its own empty data directory, driven by keys and mouse clicks like the main loop.
+/// English, no animations and an instant AI, so every run draws the same screen.
+pub struct TestApp{
+    pub state: AppState,
+    data_dir: PathBuf,
+}
+
+impl TestApp{
+    /// `name` keeps the data directories of tests running at the same time apart.
+    pub fn new(name: &str) -> TestApp{
+        TestApp::with_settings(name, |_| {})
+    }
+
+    /// App with settings changed before the files of the data directory are read.
+    pub fn with_settings(name: &str, change: impl FnOnce(&mut Settings)) -> TestApp{
+        let data_dir = std::env::temp_dir().join(format!("tictactoe-test-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&data_dir);
+
+        let mut settings = Settings::new();
+        settings.data_dir = data_dir.clone();
+        settings.language = Language::English;
+        settings.animations = false;
+        settings.ai_think_time = Duration::ZERO;
+        change(&mut settings);
+        TestApp{ state: AppState::new(settings), data_dir }
+    }
+
+    pub fn press(&mut self, code: KeyCode){
+        self.state.handle_event(AppEvent::Key(KeyEvent::new(code, KeyModifiers::NONE)));
+    }
+
+    pub fn press_keys(&mut self, codes: &[KeyCode]){
+        for code in codes {
+            self.press(*code);
+        }
+    }
+
+    pub fn type_text(&mut self, text: &str){
+        for character in text.chars() {
+            self.press(KeyCode::Char(character));
+        }
+    }
+
+    /// Left click at a cell of the screen as it was last rendered.
+    pub fn click(&mut self, column: u16, row: u16){
+        self.state.handle_event(AppEvent::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }));
+    }
+
+    /// One main loop tick, returns true when the screen would be drawn again.
+    pub fn tick(&mut self) -> bool{
+        self.state.handle_event(AppEvent::Tick(Instant::now()))
+    }
+
+    /// Runs main loop ticks until `done` holds, for work done in the background like AI moves.
+    pub fn tick_until(&mut self, done: impl Fn(&mut AppState) -> bool){
+        let started = Instant::now();
+        loop {
+            self.state.handle_event(AppEvent::Tick(Instant::now()));
+            if done(&mut self.state) {
+                return;
+            }
+            assert!(started.elapsed() < Duration::from_secs(5), "the app did not get there in time");
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    pub fn data_dir(&self) -> &Path{
+        &self.data_dir
+    }
+
+    /// State of the top view as written into crash reports, e.g. the board of a game.
+    pub fn describe_state(&mut self) -> String{
+        self.state.current_view().describe_state()
+    }
+
+    pub fn render(&mut self, width: u16, height: u16) -> Buffer{
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).expect("the test backend does not fail");
+        terminal.draw(|frame| self.state.draw(frame, Instant::now())).expect("the test backend does not fail");
+        terminal.backend().buffer().clone()
+    }
+
+    pub fn screen(&mut self, width: u16, height: u16) -> String{
+        buffer_text(&self.render(width, height))
+    }
+
+    /// Compares the screen with `snapshots/<name>.txt`.
+    pub fn assert_snapshot(&mut self, name: &str, width: u16, height: u16){
+        let buffer = self.render(width, height);
+        assert_snapshot(name, &buffer);
+    }
+}
+
+impl Drop for TestApp{
+    fn drop(&mut self){
+        let _ = fs::remove_dir_all(&self.data_dir);
+    }
+}
+
+/// Symbols of the buffer, one line per row with the trailing spaces cut off. Styles are not compared.
+pub fn buffer_text(buffer: &Buffer) -> String{
+    let area = buffer.area;
+    let mut text = String::new();
+    for y in area.top()..area.bottom() {
+        let line: String = (area.left()..area.right()).map(|x| buffer[(x, y)].symbol()).collect();
+        text.push_str(line.trim_end());
+        text.push('\n');
+    }
+    text
+}
+
+/// Fails with both screens when the buffer differs from the stored snapshot, missing snapshots fail too.
+pub fn assert_snapshot(name: &str, buffer: &Buffer){
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/tests/snapshots").join(format!("{}.txt", name));
+    let actual = buffer_text(buffer);
+
+    if std::env::var_os(UPDATE_VARIABLE).is_some() {
+        fs::create_dir_all(path.parent().expect("snapshots have a folder")).expect("snapshot folder can be created");
+        fs::write(&path, &actual).expect("snapshot can be written");
+        return;
+    }
+    let Ok(expected) = fs::read_to_string(&path) else {
+        panic!("no snapshot {}, run the tests with {}=1 to create it. The screen was:\n{}", path.display(), UPDATE_VARIABLE, actual);
+    };
+    if expected != actual {
+        panic!("screen differs from snapshot {}, run the tests with {}=1 if the change is wanted.\nexpected:\n{}\nactual:\n{}",
+            path.display(), UPDATE_VARIABLE, expected, actual);
+    }
+}
@@ -0,0 +1,44 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crate::enums::key_action::KeyAction;
+use crate::services::keymap::{KeyMap, KeyPreset};
+
+fn press(keymap: &KeyMap, code: KeyCode) -> Option<KeyAction>{
+    keymap.action_for(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+#[test]
+fn rebinding_a_taken_key_swaps(){
+    let mut keymap = KeyMap::from_preset(KeyPreset::Arrows);
+    keymap.rebind(KeyAction::Up, KeyCode::Down);
+    assert_eq!(keymap.keys_for(KeyAction::Up), vec![KeyCode::Down]);
+    assert_eq!(keymap.keys_for(KeyAction::Down), vec![KeyCode::Up]);
+    assert_eq!(keymap.preset, KeyPreset::Custom);
+
+    //Help keeps F1 when `?` is taken, it still has a key and nothing is swapped
+    keymap.rebind(KeyAction::Confirm, KeyCode::Char('?'));
+    assert_eq!(keymap.keys_for(KeyAction::Help), vec![KeyCode::F(1)]);
+    assert_eq!(press(&keymap, KeyCode::Enter), None);
+}
+
+#[test]
+fn every_action_keeps_a_key_after_a_rebind(){
+    let presets = [KeyPreset::Arrows, KeyPreset::Vim, KeyPreset::Wasd, KeyPreset::Numpad];
+    for preset in presets {
+        let defaults = KeyMap::from_preset(preset);
+        let mut actions = KeyAction::get_rebindable().to_vec();
+        actions.extend((0..9).map(KeyAction::Tile).filter(|tile| !defaults.keys_for(*tile).is_empty()));
+        let mut keys: Vec<KeyCode> = actions.iter().flat_map(|action| defaults.keys_for(*action)).collect();
+        keys.push(KeyCode::Char('x'));
+
+        for action in KeyAction::get_rebindable() {
+            for key in &keys {
+                let mut keymap = defaults.clone();
+                keymap.rebind(action, *key);
+                assert_eq!(press(&keymap, *key), Some(action));
+                for other in &actions {
+                    assert!(!keymap.keys_for(*other).is_empty(), "{:?} lost its key after binding {:?} to {:?} in {:?}", other, action, key, preset);
+                }
+            }
+        }
+    }
+}
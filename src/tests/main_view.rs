@@ -0,0 +1,44 @@
+use ratatui::crossterm::event::KeyCode;
+use crate::enums::ai_level::AiLevel;
+use crate::enums::field::FieldMark;
+use crate::enums::player_type::PlayerType;
+use crate::enums::variant::Variant;
+use crate::services::personality::DEFAULT_PERSONALITY;
+use crate::services::saved_game;
+use crate::services::saved_game::SavedGame;
+use crate::tests::harness::TestApp;
+
+#[test]
+fn main_menu(){
+    let mut app = TestApp::new("main-menu");
+    app.assert_snapshot("main_menu", 80, 24);
+}
+
+#[test]
+fn main_menu_compact(){
+    let mut app = TestApp::new("main-menu-compact");
+    app.assert_snapshot("main_menu_compact", 24, 14);
+}
+
+#[test]
+fn main_menu_selection_follows_keys(){
+    let mut app = TestApp::new("main-menu-keys");
+    app.press_keys(&[KeyCode::Down, KeyCode::Down, KeyCode::Up]);
+    assert!(app.screen(80, 24).contains(">>Start Local Co-op game"));
+}
+
+#[test]
+fn main_menu_offers_saved_game(){
+    let mut app = TestApp::with_settings("main-menu-saved", |settings| {
+        let saved = SavedGame{
+            opponent_type: PlayerType::Ai,
+            ai_level: AiLevel::Normal,
+            moves: vec![4, 0],
+            personality: DEFAULT_PERSONALITY.to_string(),
+            variant: Variant::Classic,
+            player_mark: FieldMark::X,
+        };
+        saved_game::save(&settings.data_dir, &saved).unwrap();
+    });
+    app.assert_snapshot("main_menu_saved_game", 80, 24);
+}
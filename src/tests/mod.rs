@@ -0,0 +1,10 @@
+//Render snapshots and key sequences through the whole app, see harness.rs for updating the snapshots
+mod harness;
+mod main_view;
+mod settings_view;
+mod game_view;
+mod network;
+mod discovery;
+mod keymap;
+mod clock;
+mod translations;
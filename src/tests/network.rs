@@ -0,0 +1,87 @@
+use std::io::Write;
+use std::net::{Ipv4Addr, SocketAddr, TcpStream};
+use std::time::{Duration, Instant};
+use crate::enums::field::FieldMark;
+use crate::services::network;
+use crate::services::network::{HostSession, Message, Peer, Role};
+
+fn host_address(host: &HostSession) -> SocketAddr{
+    SocketAddr::from((Ipv4Addr::LOCALHOST, host.port()))
+}
+
+/// Polls until a message arrives, the reading thread needs a moment on a loaded machine.
+fn next_message(peer: &mut Peer) -> Message{
+    let started = Instant::now();
+    loop {
+        if let Some(message) = peer.poll().expect("the connection stays open") {
+            return message;
+        }
+        assert!(started.elapsed() < Duration::from_secs(5), "no message arrived in time");
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+fn accept_opponent(host: &mut HostSession){
+    let started = Instant::now();
+    while host.opponent.is_none() {
+        host.update_connections().expect("the listener keeps working");
+        assert!(started.elapsed() < Duration::from_secs(5), "the client did not join in time");
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn host_and_client_play_a_move(){
+    let mut host = HostSession::bind(0).unwrap();
+    let mut client = network::join(host_address(&host), Role::Player).unwrap();
+    accept_opponent(&mut host);
+    assert_eq!(next_message(&mut client), Message::Welcome(Role::Player));
+
+    client.send(&Message::Move(4)).unwrap();
+    let opponent = host.opponent.as_mut().unwrap();
+    assert_eq!(next_message(opponent), Message::Move(4));
+
+    let mut board = [FieldMark::Empty; 9];
+    board[4] = FieldMark::O;
+    let state = Message::State { board, to_move: FieldMark::X, winner: None };
+    host.broadcast(&state).unwrap();
+    assert_eq!(next_message(&mut client), state);
+}
+
+#[test]
+fn second_player_spectates(){
+    let mut host = HostSession::bind(0).unwrap();
+    let mut player = network::join(host_address(&host), Role::Player).unwrap();
+    accept_opponent(&mut host);
+    let mut spectator = network::join(host_address(&host), Role::Player).unwrap();
+
+    let started = Instant::now();
+    while host.spectators.is_empty() {
+        host.update_connections().unwrap();
+        assert!(started.elapsed() < Duration::from_secs(5), "the spectator did not join in time");
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    assert_eq!(next_message(&mut player), Message::Welcome(Role::Player));
+    assert_eq!(next_message(&mut spectator), Message::Welcome(Role::Spectator));
+}
+
+//A client that says hello and never reads fills the socket buffers, sending to it has to fail instead of blocking
+#[test]
+fn send_to_a_stuck_client_times_out(){
+    let mut host = HostSession::bind(0).unwrap();
+    let mut stuck = TcpStream::connect(host_address(&host)).unwrap();
+    writeln!(stuck, "{}", Message::Hello { version: network::PROTOCOL_VERSION, role: Role::Player }.encode()).unwrap();
+    accept_opponent(&mut host);
+
+    let message = Message::Error("x".repeat(64 * 1024));
+    let started = Instant::now();
+    let mut failed = false;
+    for _ in 0..10_000 {
+        if host.broadcast(&message).is_err() {
+            failed = true;
+            break;
+        }
+    }
+    assert!(failed, "the sends never filled the buffers");
+    assert!(started.elapsed() < Duration::from_secs(10));
+}
@@ -0,0 +1,94 @@
+use std::fs;
+use ratatui::crossterm::event::KeyCode;
+use crate::tests::harness::TestApp;
+
+//Statistics come right before the settings in the main menu
+fn open_settings(app: &mut TestApp){
+    app.press_keys(&[KeyCode::Down; 6]);
+    app.press(KeyCode::Enter);
+}
+
+#[test]
+fn settings_screen(){
+    let mut app = TestApp::new("settings");
+    open_settings(&mut app);
+    app.assert_snapshot("settings", 80, 34);
+}
+
+#[test]
+fn settings_screen_scrolls_when_small(){
+    let mut app = TestApp::new("settings-small");
+    open_settings(&mut app);
+    app.press_keys(&[KeyCode::Down; 20]);
+    app.assert_snapshot("settings_small", 40, 16);
+}
+
+#[test]
+fn edit_colors_opens_the_color_modal(){
+    let mut app = TestApp::new("settings-colors");
+    open_settings(&mut app);
+    app.press_keys(&[KeyCode::Down; 7]);
+    app.press(KeyCode::Enter);
+    app.assert_snapshot("settings_theme_colors", 80, 34);
+
+    app.press(KeyCode::Enter);
+    app.assert_snapshot("settings_color_picker", 80, 34);
+}
+
+#[test]
+fn toggles_are_saved(){
+    let mut app = TestApp::new("settings-toggle");
+    open_settings(&mut app);
+    app.press_keys(&[KeyCode::Down; 9]);
+    assert!(app.screen(80, 34).contains(">>Animations         [ ] Off"));
+
+    app.press(KeyCode::Enter);
+    assert!(app.screen(80, 34).contains(">>Animations         [x] On"));
+    let preferences = fs::read_to_string(app.data_dir().join("settings.tsv")).unwrap();
+    assert!(preferences.contains("animations\ton"));
+}
+
+#[test]
+fn slider_stops_at_its_ends(){
+    let mut app = TestApp::new("settings-slider");
+    open_settings(&mut app);
+    app.press_keys(&[KeyCode::Down; 3]);
+    app.press_keys(&[KeyCode::Left; 2]);
+    assert!(app.screen(80, 34).contains("◀ ░░░░░░░░░░░░ ▶ 0.00 s"));
+
+    app.press_keys(&[KeyCode::Right; 20]);
+    assert!(app.screen(80, 34).contains("◀ ████████████ ▶ 3.00 s"));
+}
+
+#[test]
+fn engine_command_is_typed_and_saved(){
+    let mut app = TestApp::new("settings-engine");
+    open_settings(&mut app);
+    app.press_keys(&[KeyCode::Down; 4]);
+    assert!(app.screen(80, 34).contains(">>Engine command     not set ›"));
+
+    app.press(KeyCode::Enter);
+    app.type_text("python3 engines/random_engine.py");
+    app.assert_snapshot("settings_engine_input", 80, 34);
+
+    app.press(KeyCode::Enter);
+    assert!(app.screen(80, 34).contains(">>Engine command     python3 engines/random_engine.py ›"));
+    let preferences = fs::read_to_string(app.data_dir().join("settings.tsv")).unwrap();
+    assert!(preferences.contains("engine\tpython3 engines/random_engine.py"));
+}
+
+#[test]
+fn reset_to_defaults_asks_first(){
+    let mut app = TestApp::new("settings-reset");
+    open_settings(&mut app);
+    app.press_keys(&[KeyCode::Down, KeyCode::Right, KeyCode::Down, KeyCode::Right]);
+    app.press_keys(&[KeyCode::Down; 20]);
+    app.press(KeyCode::Up);
+    app.press(KeyCode::Enter);
+    app.assert_snapshot("settings_reset_confirm", 80, 34);
+
+    app.press(KeyCode::Char('y'));
+    let screen = app.screen(80, 34);
+    assert!(screen.contains("First move         ◀ You ▶"));
+    assert!(screen.contains("Rules              ◀ Classic ▶"));
+}
@@ -0,0 +1,95 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::enums::ai_level::AiLevel;
+use crate::enums::banner::Banner;
+use crate::enums::first_move::FirstMove;
+use crate::enums::language::Language;
+use crate::enums::notification_kind::NotificationKind;
+use crate::enums::theme_element::ThemeElement;
+use crate::enums::variant::Variant;
+use crate::services::locale;
+use crate::services::translations;
+
+/// Calls whose first string literal is shown translated, binding descriptions go through `tr` in the help.
+const TRANSLATED_CALLS: [&str; 5] = ["tr(\"", "tr_args(\"", "Binding::action(", "Binding::key(", "Binding::tiles("];
+
+fn polish_keys() -> HashSet<&'static str>{
+    translations::catalog(Language::Polish).iter().map(|(english, _)| *english).collect()
+}
+
+fn source_files(folder: &Path, files: &mut Vec<PathBuf>){
+    for entry in fs::read_dir(folder).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            //The tests type text of their own
+            if path.file_name().is_some_and(|name| name != "tests") {
+                source_files(&path, files);
+            }
+        }else if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+/// The string literal starting at the first quote of `text`, escapes are kept as written.
+fn first_literal(text: &str) -> Option<&str>{
+    let start = text.find('"')? + 1;
+    let mut escaped = false;
+    for (index, character) in text[start..].char_indices() {
+        match character {
+            '\\' if !escaped => escaped = true,
+            '"' if !escaped => return Some(&text[start..start + index]),
+            _ => escaped = false,
+        }
+    }
+    None
+}
+
+//Every literal handed to tr, tr_args or a key table has to be in the catalog, or it shows up in English
+#[test]
+fn polish_catalog_covers_the_source(){
+    let keys = polish_keys();
+    let mut files = Vec::new();
+    source_files(&PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src"), &mut files);
+
+    let mut missing = Vec::new();
+    let mut checked = 0;
+    for file in files {
+        let source = fs::read_to_string(&file).unwrap();
+        for line in source.lines() {
+            for call in TRANSLATED_CALLS {
+                //The description of a binding is its first literal, the key before it has none
+                for (start, _) in line.match_indices(call) {
+                    let Some(english) = first_literal(&line[start..]) else { continue };
+                    checked += 1;
+                    if !keys.contains(english) {
+                        missing.push(format!("{}: {}", file.display(), english));
+                    }
+                }
+            }
+        }
+    }
+    //A scan that finds nothing would pass as well
+    assert!(checked > 100, "only {} translated literals found, the scan misses calls", checked);
+    assert!(missing.is_empty(), "missing Polish translations:\n{}", missing.join("\n"));
+}
+
+//Labels built from `as_str` are not literals at the call of tr
+#[test]
+fn polish_catalog_covers_the_labels(){
+    locale::use_language(Language::English);
+    let keys = polish_keys();
+    let mut labels: Vec<String> = Vec::new();
+    labels.extend(AiLevel::get_all().iter().map(|level| level.label().to_string()));
+    labels.extend(ThemeElement::get_all().iter().map(|element| element.label().to_string()));
+    labels.extend(FirstMove::get_all().iter().map(|first_move| first_move.label().to_string()));
+    labels.extend(Variant::get_all().iter().map(|variant| variant.label().to_string()));
+    labels.extend([NotificationKind::Info, NotificationKind::Success, NotificationKind::Error].iter().map(|kind| kind.as_str().to_string()));
+    let banners = [Banner::YourTurn, Banner::EnemyTurn, Banner::CrossTurn, Banner::CircleTurn, Banner::YouWon,
+        Banner::YouLost, Banner::CrossWon, Banner::CircleWon, Banner::Draw];
+    labels.extend(banners.iter().map(|banner| banner.as_str().to_string()));
+
+    let missing: Vec<&String> = labels.iter().filter(|label| !keys.contains(label.as_str())).collect();
+    assert!(missing.is_empty(), "missing Polish translations: {:?}", missing);
+}
@@ -1,31 +1,59 @@
-use std::time::Duration;
-use ratatui::crossterm::event;
-use ratatui::crossterm::event::{Event, KeyEvent, KeyEventKind};
+use std::time::Instant;
+use ratatui::crossterm::event::{KeyEvent, MouseEvent};
 use ratatui::Frame;
+use crate::enums::dialog_result::DialogResult;
 use crate::enums::view_action::ViewAction;
+use crate::helpers::key_table::HelpEntry;
+use crate::helpers::notifications::Notification;
 
 pub trait ViewModel{
+    /// Only draws, state changes belong in the input handlers and `update`.
     fn render_widgets(&mut self, frame: &mut Frame);
     fn handle_inputs(&mut self, key: KeyEvent) -> ViewAction;
 
-    fn additional_actions(&mut self);
-
-    fn handle_events(&mut self) -> Result<ViewAction, std::io::Error>{
-        if event::poll(Duration::from_millis(16))? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press{
-                    return Ok(self.handle_inputs(key))
-                }
-            }
-        }
-        Ok(ViewAction::Nothing)
-    }
-    fn render(&mut self, frame: &mut Frame) -> ViewAction{
-        self.render_widgets(frame);
-        self.additional_actions();
-        match self.handle_events(){
-            Ok(value) => {value}
-            Err(_) => { ViewAction::Quit }
-        }
-    }
-}
\ No newline at end of file
+    /// Keys that work in the current state, shown by the help overlay.
+    fn help(&self) -> Vec<HelpEntry>{
+        Vec::new()
+    }
+
+    fn handle_mouse(&mut self, _mouse: MouseEvent) -> ViewAction{
+        ViewAction::Nothing
+    }
+
+    //Called on every tick of the main loop, also when no key was pressed
+    fn update(&mut self, _now: Instant) -> ViewAction{
+        ViewAction::Nothing
+    }
+
+    /// Called instead of `update` while other views cover this one, for work that must not stop meanwhile.
+    fn background_update(&mut self, _now: Instant){}
+
+    /// Called when the view is on top again after the views above it closed.
+    fn handle_result(&mut self, _result: DialogResult) -> ViewAction{
+        ViewAction::Nothing
+    }
+
+    /// True when the view changes without input (clocks, animations, network), it is then redrawn every tick.
+    fn is_live(&self) -> bool{
+        false
+    }
+
+    /// Messages raised since the last call, the main loop shows them as toasts and keeps them in the log.
+    fn take_notifications(&mut self) -> Vec<Notification>{
+        Vec::new()
+    }
+
+    /// Overlays are drawn on top of the view below them instead of replacing the whole screen.
+    fn is_overlay(&self) -> bool{
+        false
+    }
+
+    fn name(&self) -> &'static str{
+        std::any::type_name::<Self>().rsplit("::").next().unwrap_or("view")
+    }
+
+    /// State worth knowing when the app crashes in this view, written into the crash report.
+    fn describe_state(&self) -> String{
+        String::new()
+    }
+}
@@ -0,0 +1,236 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Constraint, Direction, Layout, Margin, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::widgets::{Block, Clear, List, ListState, Paragraph};
+use crate::enums::key_action::KeyAction;
+use crate::enums::theme_element::ThemeElement;
+use crate::enums::view_action::ViewAction;
+use crate::helpers::key_table;
+use crate::helpers::key_table::{Binding, HelpEntry};
+use crate::helpers::mouse;
+use crate::helpers::notifications::Notification;
+use crate::helpers::text_input::TextInput;
+use crate::services::locale::{tr, tr_args};
+use crate::services::settings::Settings;
+use crate::services::theme;
+use crate::traits::view_model::ViewModel;
+
+const CUSTOM_COLOR_TEXT: &str = "Custom (hex or r,g,b)";
+
+/// Overlay for picking the color of one theme element, the last row takes a typed color.
+pub struct ColorPickerView{
+    element: ThemeElement,
+    color_list: ListState,
+    color_options: Vec<ColorOption>,
+    color_list_area: Rect,
+    color_input: TextInput,
+    editing_custom: bool,
+    settings: Rc<RefCell<Settings>>,
+    notifications: Vec<Notification>,
+}
+
+#[derive(Debug,Clone,Copy)]
+pub enum ColorOption{
+    Green,
+    Blue,
+    Magenta,
+    Yellow,
+    Red,
+    Miku,
+    MorningSun,
+    Pink,
+    Orange
+}
+
+impl ColorOption{
+    pub fn as_color(&self) -> Color{
+        match self {
+            ColorOption::Green => {Color::Green}
+            ColorOption::Blue => {Color::Blue}
+            ColorOption::Magenta => {Color::Magenta}
+            ColorOption::Yellow => {Color::Yellow},
+            ColorOption::Red => {Color::Red},
+            ColorOption::Miku => {Color::Rgb(134,206,203)},
+            ColorOption::MorningSun => {Color::Rgb(255,255,26)}
+            ColorOption::Pink => {Color::Rgb(255,51,255)}
+            ColorOption::Orange => {Color::Rgb(255,102,0)}
+        }
+    }
+
+    pub fn get_all() -> [ColorOption; 9]{
+        [
+            ColorOption::Green,
+            ColorOption::Blue,
+            ColorOption::Magenta,
+            ColorOption::Yellow,
+            ColorOption::Red,
+            ColorOption::Miku,
+            ColorOption::MorningSun,
+            ColorOption::Pink,
+            ColorOption::Orange
+        ]
+    }
+}
+
+impl ColorPickerView{
+    pub fn new(element: ThemeElement, settings: Rc<RefCell<Settings>>) -> ColorPickerView{
+        let mut color_list = ListState::default();
+        color_list.select(Some(0));
+
+        ColorPickerView{
+            element,
+            color_list,
+            color_options: ColorOption::get_all().to_vec(),
+            color_list_area: Rect::default(),
+            color_input: TextInput::new("", 24),
+            editing_custom: false,
+            settings,
+            notifications: Vec::new(),
+        }
+    }
+
+    fn get_selected_color_option(&self) -> Option<&ColorOption> {
+        self.color_list.selected().and_then(|index| self.color_options.get(index))
+    }
+
+    fn set_color(&mut self, color: Color) -> ViewAction{
+        let element = self.element;
+        if let Err(error) = self.settings.borrow_mut().change_theme(|theme| theme.set_color(element, color)) {
+            self.notifications.push(Notification::error(tr_args("Could not save theme: {}", &[&error])));
+        }
+        ViewAction::Back
+    }
+
+    /// Applies the selected color, the row after the predefined colors starts the custom color input.
+    fn select_color_option(&mut self) -> ViewAction{
+        if let Some(color_option) = self.get_selected_color_option().copied(){
+            return self.set_color(color_option.as_color());
+        }
+        let current = self.settings.borrow().theme.color(self.element);
+        self.color_input = TextInput::new(&current.to_string(), 24);
+        self.editing_custom = true;
+        ViewAction::Nothing
+    }
+
+    fn apply_custom_color(&mut self) -> ViewAction{
+        match theme::parse_color(self.color_input.value()) {
+            Some(color) => self.set_color(color),
+            None => {
+                self.notifications.push(Notification::error(tr_args("Unknown color {}, use #rrggbb, r,g,b or a color name", &[&self.color_input.value()])));
+                ViewAction::Nothing
+            }
+        }
+    }
+
+    //Typed text goes to the input, so only Enter and Esc are handled here and not the keymap
+    //While typing a custom color only fixed keys work, everything else goes to the input
+    fn bindings(&self) -> Vec<Binding<ColorPickerView>>{
+        if self.editing_custom {
+            return vec![
+                Binding::key(KeyCode::Enter, "Apply color", |view, _| view.apply_custom_color()),
+                Binding::key(KeyCode::Esc, "Cancel", |view, _| {view.editing_custom = false; ViewAction::Nothing}),
+                Binding::typing_help(),
+            ];
+        }
+        vec![
+            Binding::action(KeyAction::Up, "Select", |view, _| {view.color_list.select_previous(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Down, "Select", |view, _| {view.color_list.select_next(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Confirm, "Apply color", |view, _| view.select_color_option()),
+            Binding::action(KeyAction::Back, "Back", |_, _| ViewAction::Back),
+            Binding::help(),
+        ]
+    }
+}
+
+impl ViewModel for ColorPickerView{
+    fn render_widgets(&mut self, frame: &mut Frame){
+        let theme = self.settings.borrow().theme.clone();
+        let popup_area = frame.area().centered(Constraint::Percentage(50),Constraint::Percentage(60));
+        let popup_area_rects = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Fill(1),Constraint::Length(4),Constraint::Length(1)])
+            .split(popup_area.inner(Margin::new(1,1)));
+
+        let block = Block::bordered().style(theme.base_style()).border_style(theme.border_style()).title(tr_args("Select {} color", &[&self.element.label()])).title_alignment(Alignment::Center);
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(block, popup_area);
+
+        let mut list_items = self.color_options.iter().map(|color| format!("{:?}",color)).collect::<Vec<String>>();
+        list_items.push(tr(CUSTOM_COLOR_TEXT).to_string());
+        let list = List::new(list_items)
+            .highlight_style(theme.highlight_style())
+            .highlight_symbol(">>")
+            .repeat_highlight_symbol(true);
+        frame.render_stateful_widget(list, popup_area_rects[0], &mut self.color_list);
+        self.color_list_area = popup_area_rects[0];
+
+        //Color preview, below the input while a custom color is typed
+        let mut preview_area = popup_area_rects[1];
+        let color = if self.editing_custom {
+            let input_area = Rect { height: 3, ..preview_area };
+            self.color_input.render(frame, input_area, tr("#rrggbb, r,g,b or name"));
+            preview_area = Rect { y: input_area.bottom(), height: 1, ..preview_area };
+            theme::parse_color(self.color_input.value())
+        }else{
+            Some(self.get_selected_color_option().map(|color| color.as_color()).unwrap_or(theme.color(self.element)))
+        };
+        if let Some(color) = color {
+            frame.render_widget(Block::new().style(Style::new().bg(color)), preview_area.inner(Margin::new(1,0)));
+        }
+
+        let keymap = &self.settings.borrow().keymap;
+        let help_text = if self.editing_custom {
+            tr("Enter - apply, ESC - cancel").to_string()
+        }else{
+            tr_args("{} - apply, {} - back", &[&keymap.label_for(KeyAction::Confirm), &keymap.label_for(KeyAction::Back)])
+        };
+        frame.render_widget(Paragraph::new(help_text).centered(), popup_area_rects[2]);
+    }
+
+    fn handle_inputs(&mut self, key: KeyEvent) -> ViewAction{
+        let action = self.settings.borrow().keymap.action_for(key);
+        let bindings = self.bindings();
+        if let Some(view_action) = key_table::dispatch(self, &bindings, action, key) {
+            return view_action;
+        }
+        if self.editing_custom {
+            self.color_input.handle_key(key);
+        }
+        ViewAction::Nothing
+    }
+
+    fn help(&self) -> Vec<HelpEntry>{
+        key_table::help_entries(&self.bindings(), &self.settings.borrow().keymap)
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) -> ViewAction{
+        if self.editing_custom {
+            return ViewAction::Nothing;
+        }
+        //One more row than colors for the custom color entry
+        let item_count = self.color_options.len() + 1;
+        let Some(index) = mouse::list_item_at(self.color_list_area, &self.color_list, item_count, event.column, event.row) else {
+            return ViewAction::Nothing
+        };
+        match event.kind {
+            MouseEventKind::Moved => self.color_list.select(Some(index)),
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.color_list.select(Some(index));
+                return self.select_color_option();
+            }
+            _ => {}
+        }
+        ViewAction::Nothing
+    }
+
+    fn is_overlay(&self) -> bool{
+        true
+    }
+
+    fn take_notifications(&mut self) -> Vec<Notification>{
+        std::mem::take(&mut self.notifications)
+    }
+}
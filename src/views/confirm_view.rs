@@ -0,0 +1,112 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Constraint, Direction, Layout, Margin, Rect};
+use ratatui::widgets::{Block, BorderType, Clear, List, ListState, Paragraph, Wrap};
+use crate::enums::confirmation::Confirmation;
+use crate::enums::dialog_result::DialogResult;
+use crate::enums::key_action::KeyAction;
+use crate::enums::view_action::ViewAction;
+use crate::helpers::key_table;
+use crate::helpers::key_table::{Binding, HelpEntry};
+use crate::helpers::mouse;
+use crate::services::locale::{tr, tr_args};
+use crate::services::settings::Settings;
+use crate::traits::view_model::ViewModel;
+
+const ANSWERS: [&str; 2] = ["No", "Yes"];
+
+/// Yes/no overlay, No is selected first so a hasty Enter keeps things as they are.
+pub struct ConfirmView{
+    confirmation: Confirmation,
+    list: ListState,
+    list_area: Rect,
+    settings: Rc<RefCell<Settings>>,
+}
+
+impl ConfirmView{
+    pub fn new(confirmation: Confirmation, settings: Rc<RefCell<Settings>>) -> ConfirmView{
+        let mut list = ListState::default();
+        list.select(Some(0));
+
+        ConfirmView{
+            confirmation,
+            list,
+            list_area: Rect::default(),
+            settings,
+        }
+    }
+
+    fn answer(&self, yes: bool) -> ViewAction{
+        if yes {ViewAction::Return(DialogResult::Confirmed(self.confirmation))} else {ViewAction::Back}
+    }
+
+    //The letters come first so y and n answer even when the keymap uses them
+    fn bindings(&self) -> Vec<Binding<ConfirmView>>{
+        vec![
+            Binding::key(KeyCode::Char('y'), "Yes", |view, _| view.answer(true)),
+            Binding::key(KeyCode::Char('n'), "No", |view, _| view.answer(false)),
+            Binding::action(KeyAction::Back, "No", |view, _| view.answer(false)),
+            Binding::action(KeyAction::Up, "Select", |view, _| {view.list.select_previous(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Down, "Select", |view, _| {view.list.select_next(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Confirm, "Answer", |view, _| view.answer(view.list.selected() == Some(1))),
+            Binding::help(),
+        ]
+    }
+}
+
+impl ViewModel for ConfirmView{
+    fn render_widgets(&mut self, frame: &mut Frame){
+        let theme = self.settings.borrow().theme.clone();
+        let popup_area = frame.area().centered(Constraint::Length(44),Constraint::Length(9));
+        let popup_area_rects = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2),Constraint::Length(1),Constraint::Length(2),Constraint::Length(1)])
+            .split(popup_area.inner(Margin::new(1,1)));
+
+        let block = Block::bordered().border_type(BorderType::Rounded).style(theme.base_style()).border_style(theme.border_style()).title(tr("Are you sure?")).title_alignment(Alignment::Center);
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(block, popup_area);
+
+        frame.render_widget(Paragraph::new(self.confirmation.question()).centered().wrap(Wrap { trim: true }), popup_area_rects[0]);
+
+        let list_area = popup_area_rects[2].centered_horizontally(Constraint::Length(8));
+        let list = List::new(ANSWERS.map(tr))
+            .highlight_style(theme.highlight_style())
+            .highlight_symbol(">>")
+            .repeat_highlight_symbol(true);
+        frame.render_stateful_widget(list, list_area, &mut self.list);
+        self.list_area = list_area;
+
+        let keymap = &self.settings.borrow().keymap;
+        let help_text = tr_args("{} - answer, Y/N, {} - no", &[&keymap.label_for(KeyAction::Confirm), &keymap.label_for(KeyAction::Back)]);
+        frame.render_widget(Paragraph::new(help_text).centered(), popup_area_rects[3]);
+    }
+
+    fn handle_inputs(&mut self, key: KeyEvent) -> ViewAction{
+        let action = self.settings.borrow().keymap.action_for(key);
+        let bindings = self.bindings();
+        key_table::dispatch(self, &bindings, action, key).unwrap_or(ViewAction::Nothing)
+    }
+
+    fn help(&self) -> Vec<HelpEntry>{
+        key_table::help_entries(&self.bindings(), &self.settings.borrow().keymap)
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) -> ViewAction{
+        let Some(index) = mouse::list_item_at(self.list_area, &self.list, ANSWERS.len(), event.column, event.row) else {
+            return ViewAction::Nothing
+        };
+        match event.kind {
+            MouseEventKind::Moved => self.list.select(Some(index)),
+            MouseEventKind::Down(MouseButton::Left) => return self.answer(index == 1),
+            _ => {}
+        }
+        ViewAction::Nothing
+    }
+
+    fn is_overlay(&self) -> bool{
+        true
+    }
+}
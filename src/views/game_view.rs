@@ -1,43 +1,271 @@
 use std::cell::RefCell;
+use std::fmt::Display;
+use std::net::SocketAddr;
 use std::rc::Rc;
-use std::thread;
-use std::time::Duration;
-use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use std::time::{Duration, Instant};
+use ratatui::crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::Frame;
 use ratatui::layout::{Alignment, Constraint, Direction, Layout, Margin, Rect};
-use ratatui::prelude::Color;
 use ratatui::style::Style;
 use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{Block, BorderType, Borders, Gauge, Paragraph, Wrap};
+use crate::enums::banner::Banner;
+use crate::enums::confirmation::Confirmation;
+use crate::enums::dialog_result::DialogResult;
 use crate::enums::field::FieldMark;
+use crate::enums::key_action::KeyAction;
+use crate::enums::layout_size::{LayoutSize, SIDE_PANEL_MIN_WIDTH};
+use crate::enums::outcome::Outcome;
+use crate::enums::pause_option::PauseOption;
 use crate::enums::player::Player;
 use crate::enums::player_type::PlayerType;
+use crate::enums::situation::Situation;
+use crate::enums::variant::Variant;
 use crate::enums::view_action::ViewAction;
+use crate::helpers::animation;
+use crate::helpers::animation::Animation;
+use crate::helpers::key_table;
+use crate::helpers::key_table::{Binding, HelpEntry};
+use crate::helpers::mouse;
+use crate::helpers::notifications::Notification;
+use crate::services::ai_worker::AiWorker;
+use crate::services::clock;
+use crate::services::clock::GameClock;
+use crate::services::engine::{Engine, EngineError, EngineLauncher};
+use crate::services::game;
 use crate::services::game::Game;
+use crate::services::discovery;
+use crate::services::network;
+use crate::services::network::{HostSession, Message, NetworkError, RemoteSession, Role};
 use crate::traits::view_model::ViewModel;
 use crate::services::game_art as Art;
+use crate::services::locale::{tr, tr_args};
+use crate::services::personality;
+use crate::services::personality::Personality;
+use crate::services::profiles::ProfileStore;
+use crate::services::records;
+use crate::services::records::GameRecord;
+use crate::services::saved_game;
+use crate::services::saved_game::SavedGame;
 use crate::services::settings::Settings;
 
+const MARK_DRAW_TIME: Duration = Duration::from_millis(250);
+const BANNER_SLIDE_TIME: Duration = Duration::from_millis(400);
+const WIN_FLASH_TIME: Duration = Duration::from_millis(1200);
+const WIN_FLASH_PERIOD: Duration = Duration::from_millis(150);
+const BLINK_INTERVAL: Duration = Duration::from_millis(3000);
+const BLINK_TIME: Duration = Duration::from_millis(150);
+const MAX_ANNOUNCEMENTS: usize = 100;
+const TILE_NAMES: [&str; 9] = ["top left", "top", "top right", "left", "center", "right", "bottom left", "bottom", "bottom right"];
+
 pub struct AiGameView{
     game: Game,
     opponent_type: PlayerType,
     settings: Rc<RefCell<Settings>>,
+    profiles: Rc<RefCell<ProfileStore>>,
+    recorded: bool,
+    /// The selected one against the AI, engines get the classic lines.
+    personality: Personality,
+    /// Line the AI says and the situation it was picked for.
+    ai_line: Option<(Situation, String)>,
+    /// Block or blunder of the last move, the AI comments on it until the next move.
+    moment: Option<Situation>,
+    judged_moves: usize,
+    clock: Option<GameClock>,
     field_selection: u8,
-    ai_thinking_gauge: u16,
+    board_tiles: Vec<Rect>,
+    ai_worker: Option<AiWorker>,
+    engine: Option<Engine>,
+    /// Set while the engine starts, its handshake runs on a background thread.
+    engine_launcher: Option<EngineLauncher>,
+    remote: Option<RemoteSession>,
+    spectating: bool,
+    spectator_count: usize,
+    opponent_error: Option<String>,
+    notifications: Vec<Notification>,
+    opened_at: Instant,
+    /// Whether the AI face was last drawn with closed eyes, the view is live until it matches the blink timer.
+    drawn_blinking: bool,
+    /// Board as it was last seen by `update_animations`, new marks are detected against it.
+    shown_board: [FieldMark; 9],
+    placed_at: [Option<Instant>; 9],
+    finished_at: Option<Instant>,
+    /// One line per move or change of turn, shown in plain text mode.
+    announcements: Vec<String>,
+    /// Board and banner as last announced, `None` until the first update.
+    announced: Option<([FieldMark; 9], Banner)>,
 }
 
 impl AiGameView{
 
-    pub fn new(opponent_type: PlayerType, settings: Rc<RefCell<Settings>>) -> AiGameView{
-        AiGameView{
+    pub fn new(opponent_type: PlayerType, settings: Rc<RefCell<Settings>>, profiles: Rc<RefCell<ProfileStore>>) -> AiGameView{
+        let personality = if opponent_type == PlayerType::Ai {settings.borrow().personality.clone()} else {Personality::classic()};
+        //Network games are not timed, each side would run its own clock
+        let time_control = settings.borrow().time_control;
+        let first_move = settings.borrow().first_move;
+        let variant = settings.borrow().variant;
+        let clock = if time_control.is_unlimited() || opponent_type == PlayerType::Remote {
+            None
+        }else{
+            Some(GameClock::new(time_control, Instant::now()))
+        };
+        let mut view = AiGameView{
             game: Game::new(),
             field_selection: 7,
-            ai_thinking_gauge: 0,
+            board_tiles: Vec::new(),
+            ai_worker: None,
+            engine: None,
+            engine_launcher: None,
+            remote: None,
+            spectating: false,
+            spectator_count: 0,
+            opponent_error: None,
+            notifications: Vec::new(),
+            opened_at: Instant::now(),
+            drawn_blinking: false,
+            shown_board: [FieldMark::Empty; 9],
+            placed_at: [None; 9],
+            finished_at: None,
+            announcements: Vec::new(),
+            announced: None,
+            recorded: false,
+            personality,
+            ai_line: None,
+            moment: None,
+            judged_moves: 0,
+            clock,
             opponent_type,
             settings,
+            profiles,
+        };
+        if matches!(opponent_type, PlayerType::Ai | PlayerType::Engine) && !first_move.player_starts() {
+            view.game = Game::with_marks(FieldMark::O, FieldMark::X);
+        }
+        //Engines and network peers only know the classic rules
+        if matches!(opponent_type, PlayerType::Ai | PlayerType::Human) {
+            view.game.variant = variant;
+        }
+        if opponent_type == PlayerType::Engine {
+            view.start_engine();
+        }
+        view
+    }
+
+    /// Local game continued from the pause menu save, the clock starts over.
+    pub fn from_saved(saved: &SavedGame, settings: Rc<RefCell<Settings>>, profiles: Rc<RefCell<ProfileStore>>) -> AiGameView{
+        let mut view = AiGameView::new(saved.opponent_type, settings, profiles);
+        view.game = if saved.player_mark == FieldMark::O {Game::with_marks(FieldMark::O, FieldMark::X)} else {Game::new()};
+        view.game.variant = saved.variant;
+        let mut personality = Personality::find(&view.settings.borrow().data_dir, &saved.personality);
+        personality.level = saved.ai_level;
+        view.personality = personality;
+        for index in &saved.moves {
+            if let Err(message) = view.game.make_move(*index) {
+                view.show_error(tr_args("Saved game is broken: {}", &[&message]));
+                break;
+            }
+        }
+        //Marks of the saved game are already on the board, only new ones are animated
+        view.shown_board = view.game.board;
+        view.judged_moves = view.game.moves.len();
+        view
+    }
+
+    /// Network game where this side owns the game and plays cross.
+    pub fn host(settings: Rc<RefCell<Settings>>, profiles: Rc<RefCell<ProfileStore>>) -> AiGameView{
+        let port = settings.borrow().network_port;
+        let discovery_port = settings.borrow().discovery_port;
+        let mut view = AiGameView::new(PlayerType::Remote, settings, profiles);
+        match HostSession::bind(port) {
+            Ok(mut session) => {
+                session.advertise(discovery_port, discovery::local_host_name());
+                view.remote = Some(RemoteSession::Host(session));
+            }
+            Err(error) => {view.report_opponent_error(error)}
+        }
+        view
+    }
+
+    /// Network game joined at `address`, this side plays circle unless it only spectates.
+    pub fn join(address: SocketAddr, role: Role, settings: Rc<RefCell<Settings>>, profiles: Rc<RefCell<ProfileStore>>) -> AiGameView{
+        let mut view = AiGameView::new(PlayerType::Remote, settings, profiles);
+        if role == Role::Player {
+            view.game = Game::with_marks(FieldMark::O, FieldMark::X);
+        }
+        view.spectating = role == Role::Spectator;
+        match network::join(address, role) {
+            Ok(peer) => {view.remote = Some(RemoteSession::Client(peer))}
+            Err(error) => {view.report_opponent_error(error)}
+        }
+        view
+    }
+
+    fn start_engine(&mut self){
+        let config = self.settings.borrow().engine.clone();
+        match config {
+            Some(config) => {self.engine_launcher = Some(EngineLauncher::start(config))}
+            None => {self.report_opponent_error(EngineError::NotConfigured)}
+        }
+    }
+
+    /// Takes over the engine once it started, moves are only requested after that.
+    fn poll_engine_launcher(&mut self){
+        let Some(result) = self.engine_launcher.as_ref().and_then(EngineLauncher::poll) else { return };
+        self.engine_launcher = None;
+        match result {
+            Ok(engine) => {self.engine = Some(engine)}
+            Err(error) => {self.report_opponent_error(error)}
+        }
+    }
+
+    fn restart(&mut self){
+        let personality = self.personality.clone();
+        *self = AiGameView::new(self.opponent_type, Rc::clone(&self.settings), Rc::clone(&self.profiles));
+        self.personality = personality;
+    }
+
+    fn save(&mut self){
+        let saved = SavedGame {
+            opponent_type: self.opponent_type,
+            ai_level: self.personality.level,
+            moves: self.game.moves.clone(),
+            personality: self.personality.name.clone(),
+            variant: self.game.variant,
+            player_mark: self.game.player_mark,
+        };
+        let data_dir = self.settings.borrow().data_dir.clone();
+        match saved_game::save(&data_dir, &saved) {
+            Ok(()) => self.notifications.push(Notification::success(tr("Game saved, continue it from the main menu"))),
+            Err(error) => self.show_error(tr_args("Could not save game: {}", &[&error])),
+        }
+    }
+
+    /// True when leaving would throw away a started game.
+    fn is_in_progress(&self) -> bool{
+        self.game.winner.is_none() && self.game.board.iter().any(|field| *field != FieldMark::Empty)
+    }
+
+    //The clock stands still while the pause menu, its dialogs or the settings are open
+    fn pause(&mut self) -> ViewAction{
+        if let Some(clock) = self.clock.as_mut() {
+            clock.pause(Instant::now());
+        }
+        ViewAction::OpenPauseMenu(self.opponent_type)
+    }
+
+    fn resume(&mut self){
+        if let Some(clock) = self.clock.as_mut() {
+            clock.resume(Instant::now());
         }
     }
 
+    fn report_opponent_error(&mut self, error: impl Display){
+        self.opponent_error = Some(error.to_string());
+        self.notifications.push(Notification::error(error.to_string()));
+        self.engine = None;
+        self.remote = None;
+    }
+
     fn get_board_tiles(centered_board: Rect) -> Vec<Rect>{
         let mut fields: Vec<Rect> = Vec::new();
 
@@ -68,32 +296,111 @@ impl AiGameView{
     }
 
 
-    fn get_field_mark_art(&self, field_mark: FieldMark) -> Text<'static>{
-        let mark = match field_mark {
-            FieldMark::X => {Art::x()},
-            FieldMark::O => {Art::o()}
-            FieldMark::Empty => {Text::from("")}
+    /// Board height and width in rows and columns, tiles grow with the marks drawn in them.
+    fn get_board_size(size: LayoutSize) -> (u16, u16){
+        match size {
+            LayoutSize::Compact => (9, 21),
+            LayoutSize::Normal => (18, 33),
+            LayoutSize::Large => (24, 42),
+        }
+    }
+
+    fn get_field_mark_art(&self, field_mark: FieldMark, size: LayoutSize) -> Text<'static>{
+        let mark = match (field_mark, size) {
+            (FieldMark::Empty, _) => {Text::from("")}
+            (_, LayoutSize::Compact) => {Text::from(field_mark.as_char().to_ascii_uppercase().to_string())}
+            (FieldMark::X, LayoutSize::Normal) => {Art::x()},
+            (FieldMark::O, LayoutSize::Normal) => {Art::o()}
+            (FieldMark::X, LayoutSize::Large) => {Art::x_large()},
+            (FieldMark::O, LayoutSize::Large) => {Art::o_large()}
         };
         let style = self.get_style_by_mark(field_mark);
         mark.style(style)
     }
 
-    fn get_controls_text() -> Text<'static>{
-        let controls: Vec<Line> = vec![
-            Line::from(vec![Span::styled("←,↑,↓,→",Style::new().fg(Color::Magenta)),Span::styled(" - select tile",Style::new())]),
-            Line::from(""),
-            Line::from(vec![Span::styled("Enter",Style::new().fg(Color::Magenta)),Span::styled(" - confirm selection",Style::new())]),
-            Line::from(""),
-            Line::from(vec![Span::styled("ESC",Style::new().fg(Color::Magenta)),Span::styled(" - exit the game",Style::new())])
-        ];
+    /// Controls panel text, generated from the key table and the active keymap so rebound keys show up.
+    fn get_controls_text(&self) -> Text<'static>{
+        let settings = self.settings.borrow();
+        let bindings = if self.game.winner.is_some() {self.bindings()} else {Self::move_bindings()};
+        let key_style = settings.theme.accent_style();
+
+        let mut controls: Vec<Line> = Vec::new();
+        for entry in key_table::help_entries(&bindings, &settings.keymap) {
+            if !controls.is_empty() {
+                controls.push(Line::from(""));
+            }
+            controls.push(Line::from(vec![Span::styled(entry.keys, key_style), Span::raw(format!(" - {}", entry.description.to_lowercase()))]));
+        }
         Text::from(controls)
     }
 
 
+    fn draw_side_panels(&mut self, frame: &mut Frame, right_area: Rect){
+        let separated_right_area = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(right_area);
+        let right_top = separated_right_area[0];
+        let right_bottom = separated_right_area[1];
+
+        let border_style = self.settings.borrow().theme.border_style();
+        frame.render_widget(Block::new().borders(Borders::ALL).border_type(BorderType::Rounded).border_style(border_style).title(self.get_status_title()).title_alignment(Alignment::Center), right_top);
+        frame.render_widget(Block::new().borders(Borders::ALL).border_type(BorderType::Rounded).border_style(border_style).title(tr("Controls")).title_alignment(Alignment::Center), right_bottom);
+
+        //Drawing ai status
+        self.draw_ai_status(frame,right_top);
+
+        //Drawing right screen controls
+        let controls_rect = right_bottom.inner(Margin::new(2,2));
+
+        let controls_paragraph = Paragraph::new(self.get_controls_text()).wrap(Wrap { trim: true });
+        frame.render_widget(controls_paragraph.centered(), controls_rect);
+    }
+
+    /// One line replacement for the status panel when the side panels do not fit.
+    fn get_opponent_status_line(&self) -> Option<String>{
+        if self.opponent_type == PlayerType::Human {
+            return None;
+        }
+        let text = if let Some(error) = &self.opponent_error {
+            error.clone()
+        }else if self.opponent_type == PlayerType::Remote {
+            self.get_remote_status_text()
+        }else if self.engine_launcher.is_some() {
+            tr("connecting to engine...").to_string()
+        }else if self.game.winner.is_none() && self.game.current_player == Player::Opponent {
+            let progress = match &self.engine {
+                Some(engine) => engine.thinking_progress(),
+                None => self.ai_thinking_progress(),
+            };
+            tr_args("thinking {}%", &[&progress])
+        }else{
+            tr("waiting for turn..").to_string()
+        };
+        Some(format!("{}: {}", self.get_status_title(), text))
+    }
+
     fn draw_ai_status(&mut self, frame: &mut Frame, right_top: Rect){
-        if(self.opponent_type != PlayerType::Ai){
+        if self.opponent_type == PlayerType::Human {
             let right_top_middle = right_top.centered_vertically(Constraint::Length(3)).inner(Margin::new(1,1));
-           frame.render_widget(Paragraph::new("OFFLINE").centered(),right_top_middle);
+           frame.render_widget(Paragraph::new(tr("OFFLINE")).centered(),right_top_middle);
+        }
+        else if let Some(error) = &self.opponent_error {
+            let error_paragraph = Paragraph::new(error.as_str())
+                .style(self.settings.borrow().theme.loss_style())
+                .wrap(Wrap { trim: true })
+                .centered();
+            frame.render_widget(error_paragraph, right_top.inner(Margin::new(2,2)));
+        }
+        else if self.opponent_type == PlayerType::Remote {
+            self.draw_remote_status(frame, right_top);
+        }
+        else if self.engine_launcher.is_some() {
+            let connecting_paragraph = Paragraph::new(tr("connecting to engine..."))
+                .style(self.get_opponent_style())
+                .wrap(Wrap { trim: true })
+                .centered();
+            frame.render_widget(connecting_paragraph, right_top.inner(Margin::new(2,2)).centered_vertically(Constraint::Length(3)));
         }
         else{
             let right_top_separation = Layout::default()
@@ -103,324 +410,950 @@ impl AiGameView{
 
             self.draw_ai_face_and_text(right_top_separation[1],right_top_separation[0],frame);
 
-            self.draw_ai_gauge_and_advance_thinking(right_top_separation[2],frame);
+            if self.opponent_type == PlayerType::Engine {
+                self.draw_engine_gauge(right_top_separation[2],frame);
+            }else{
+                self.draw_ai_gauge(right_top_separation[2],frame);
+            }
         }
 
     }
-    fn draw_ai_face_and_text(&mut self, text_area: Rect, face_area: Rect, frame: &mut Frame){
-        let mut face = Art::smiley_face();
-        let mut ai_text = "waiting for turn..";
-        if(self.game.winner.is_none()){
-            if(self.game.current_player == Player::Player){
-                face = Art::smiley_face();
-                ai_text = "waiting for turn..";
-            }
-            else{
-                face = Art::thinking_face();
-                ai_text = "calculating move";
-            };
-        }
-        else if let Some(winner) = self.game.winner{
-            if(winner == self.game.opponent_mark){
-                face = Art::happy_face();
-                ai_text = "Yay i won, you suck";
-            }
-            else if(winner == FieldMark::Empty){
-                face = Art::angry_face();
-                ai_text = "You are as bad as me";
-            }
-            else{
-                face = Art::angry_face();
-                ai_text = "I will remember that";
+
+    fn get_remote_status_text(&self) -> String{
+        match &self.remote {
+            Some(RemoteSession::Host(session)) if session.opponent.is_none() => {
+                tr_args("waiting for player to join on port {}", &[&session.port()])
             }
+            _ if self.game.winner.is_some() => tr("game over").to_string(),
+            _ if self.spectating => tr("spectating").to_string(),
+            _ if self.game.current_player == Player::Opponent => tr("waiting for remote player").to_string(),
+            _ => tr("your move").to_string(),
+        }
+    }
+
+    fn draw_remote_status(&self, frame: &mut Frame, right_top: Rect){
+        let text = self.get_remote_status_text();
+
+        let status_paragraph = Paragraph::new(text)
+            .style(self.get_opponent_style())
+            .wrap(Wrap { trim: true })
+            .centered();
+        frame.render_widget(status_paragraph, right_top.inner(Margin::new(2,2)).centered_vertically(Constraint::Length(3)));
+    }
+
+    fn get_status_title(&self) -> String{
+        match (&self.engine, &self.remote, self.opponent_type) {
+            (Some(engine), _, _) => tr_args("Engine: {}", &[&engine.name()]),
+            (_, Some(RemoteSession::Host(session)), _) => match &session.opponent {
+                Some(peer) => tr_args("Remote: {}", &[&peer.address()]),
+                None => tr("Hosting").to_string(),
+            },
+            (_, Some(RemoteSession::Client(peer)), _) => tr_args("Host: {}", &[&peer.address()]),
+            (None, None, PlayerType::Engine) => tr("Engine").to_string(),
+            (None, None, PlayerType::Remote) => tr("Remote").to_string(),
+            (None, None, PlayerType::Ai) => self.personality.name.clone(),
+            _ => tr("AI Status").to_string(),
+        }
+    }
+    fn get_situation(&self) -> Situation{
+        match self.game.winner {
+            Some(FieldMark::Empty) => Situation::Draw,
+            Some(winner) if winner == self.game.opponent_mark => Situation::Winning,
+            Some(_) => Situation::Losing,
+            None => match (self.moment, self.game.current_player) {
+                (Some(moment), _) => moment,
+                (None, Player::Player) => Situation::Waiting,
+                (None, Player::Opponent) => Situation::Thinking,
+            },
+        }
+    }
+
+    /// Picks a new line whenever the situation changes, blocks and blunders are judged once per move.
+    fn update_ai_line(&mut self){
+        if self.game.moves.len() != self.judged_moves {
+            self.judged_moves = self.game.moves.len();
+            self.moment = personality::judge_last_move(&self.game);
+        }
+        let situation = self.get_situation();
+        if self.ai_line.as_ref().is_some_and(|(shown, _)| *shown == situation) {
+            return;
+        }
+        let line = self.personality.line(situation);
+        if self.opponent_type == PlayerType::Ai {
+            self.announce(format!("{}: {}", self.personality.name, tr(&line)));
         }
+        self.ai_line = Some((situation, line));
+    }
 
+    fn draw_ai_face_and_text(&mut self, text_area: Rect, face_area: Rect, frame: &mut Frame){
+        let (situation, ai_text) = self.ai_line.clone().unwrap_or((self.get_situation(), String::new()));
+        let mut face = self.personality.face(situation);
 
+        self.drawn_blinking = self.is_blinking(Instant::now());
+        if self.drawn_blinking {
+            face = Self::close_eyes(face);
+        }
         let face = face.style(self.get_opponent_style());
         frame.render_widget(face.centered(),face_area.inner(Margin::new(0,1)));
 
-        frame.render_widget(Paragraph::new(ai_text).centered(),text_area.centered_vertically(Constraint::Length(1)));
+        //Built in lines are in the catalog, lines from personality files are shown as written. Long lines wrap
+        let ai_text = tr(&ai_text).to_string();
+        let height = (ai_text.chars().count() as u16).div_ceil(text_area.width.max(1)).clamp(1, 3);
+        frame.render_widget(Paragraph::new(ai_text).centered().wrap(Wrap { trim: true }),text_area.centered_vertically(Constraint::Length(height)));
+    }
+
+    /// The AI face blinks for a moment every few seconds.
+    fn is_blinking(&self, now: Instant) -> bool{
+        if !self.settings.borrow().animations {
+            return false;
+        }
+        let elapsed = now.saturating_duration_since(self.opened_at).as_millis();
+        elapsed % BLINK_INTERVAL.as_millis() >= (BLINK_INTERVAL - BLINK_TIME).as_millis()
     }
 
-    fn draw_ai_gauge_and_advance_thinking(&mut self, area: Rect, frame:&mut Frame){
-        if(self.game.current_player == Player::Opponent && self.game.winner.is_none()){
+    //Eyes are the rows above the first empty row, which also works for faces from art packs
+    fn close_eyes(mut face: Text<'static>) -> Text<'static>{
+        for line in face.lines.iter_mut() {
+            if line.spans.iter().all(|span| span.content.trim().is_empty()) {
+                break;
+            }
+            for span in line.spans.iter_mut() {
+                span.content = span.content.replace('█', "▄").into();
+            }
+        }
+        face
+    }
+
+    fn draw_ai_gauge(&self, area: Rect, frame:&mut Frame){
+        if self.game.current_player == Player::Opponent && self.game.winner.is_none() {
             let gauge = Gauge::default()
-                .percent(self.ai_thinking_gauge)
+                .percent(self.ai_thinking_progress())
                 .style(self.get_opponent_style());
             frame.render_widget(gauge, area.centered_vertically(Constraint::Length(1)));
-            self.update_ai_gauge_and_wait(10);
         }
     }
 
-    fn update_ai_gauge_and_wait(&mut self, amount: u16){
-        self.ai_thinking_gauge += amount;
-        thread::sleep(Duration::from_millis(100));
+    fn ai_thinking_progress(&self) -> u16{
+        self.ai_worker.as_ref().map(AiWorker::progress).unwrap_or(0)
+    }
+
+    fn draw_engine_gauge(&self, area: Rect, frame:&mut Frame){
+        if let Some(engine) = &self.engine && engine.is_thinking() {
+            let gauge = Gauge::default()
+                .percent(engine.thinking_progress())
+                .style(self.get_opponent_style());
+            frame.render_widget(gauge, area.centered_vertically(Constraint::Length(1)));
+        }
     }
 
     fn get_player_style(&self) -> Style{
-        Style::new().fg(self.settings.borrow().player_color)
+        self.settings.borrow().theme.player_style()
     }
 
     fn get_opponent_style(&self) -> Style{
-        Style::new().fg(self.settings.borrow().opponent_color)
+        self.settings.borrow().theme.opponent_style()
     }
 
     fn get_style_by_mark(&self, field_mark: FieldMark) -> Style{
-        if(self.game.player_mark == field_mark){
+        if self.game.player_mark == field_mark {
             self.get_player_style()
-        }else if(self.game.opponent_mark == field_mark){
+        }else if self.game.opponent_mark == field_mark {
             self.get_opponent_style()
         }else{
             Style::new().gray()
         }
     }
 
-    fn get_top_text(&self) -> Text<'_>{
-        if(self.opponent_type == PlayerType::Ai){
-            self.get_top_text_ai_game()
+    fn get_banner(&self) -> (Banner, Style){
+        if self.opponent_type != PlayerType::Human && !self.spectating {
+            self.get_banner_ai_game()
         }else{
-            self.get_top_text_human_game()
+            self.get_banner_human_game()
         }
     }
 
-    fn get_top_text_ai_game(&self) -> Text<'_>{
+    /// Profiles only exist for games played on this machine.
+    fn is_local_game(&self) -> bool{
+        matches!(self.opponent_type, PlayerType::Ai | PlayerType::Human | PlayerType::Engine)
+    }
+
+    fn get_name_by_mark(&self, field_mark: FieldMark) -> String{
+        if field_mark == self.game.player_mark {
+            return self.profiles.borrow().seat_name(Player::Player);
+        }
+        match (self.opponent_type, &self.engine) {
+            (PlayerType::Human, _) => self.profiles.borrow().seat_name(Player::Opponent),
+            (PlayerType::Engine, Some(engine)) => engine.name().to_string(),
+            (PlayerType::Engine, None) => tr("Engine").to_string(),
+            _ => format!("{} ({})", self.personality.name, self.personality.level.label()),
+        }
+    }
+
+    fn get_top_caption(&self) -> Option<String>{
+        if !self.is_local_game() {
+            return None;
+        }
+        let caption = match (self.game.winner, self.game.timed_out) {
+            (None, _) => tr_args("{} (X) vs {} (O)", &[&self.get_name_by_mark(FieldMark::X), &self.get_name_by_mark(FieldMark::O)]),
+            (Some(FieldMark::Empty), _) => tr("Draw").to_string(),
+            (Some(winner_mark), Some(loser_mark)) => {
+                tr_args("{} ran out of time, {} won!", &[&self.get_name_by_mark(loser_mark), &self.get_name_by_mark(winner_mark)])
+            }
+            (Some(winner_mark), None) => tr_args("{} won!", &[&self.get_name_by_mark(winner_mark)]),
+        };
+        if self.game.variant != Variant::Classic {
+            return Some(format!("{} - {}", caption, self.game.variant.label()));
+        }
+        Some(caption)
+    }
+
+    /// Saves the finished game once, into the seat profiles and the game records.
+    fn record_finished_game(&mut self){
+        let Some(winner) = self.game.winner else { return };
+        if self.recorded || !self.is_local_game() {
+            return;
+        }
+        self.recorded = true;
+
+        let outcome_for = |mark: FieldMark| {
+            if winner == FieldMark::Empty {Outcome::Draw} else if winner == mark {Outcome::Win} else {Outcome::Loss}
+        };
+        let player_outcome = outcome_for(self.game.player_mark);
+        let opponent_outcome = outcome_for(self.game.opponent_mark);
+
+        let mut record = GameRecord::new(self.opponent_type, &self.get_name_by_mark(FieldMark::X), &self.get_name_by_mark(FieldMark::O), winner, &self.game.moves);
+
+        let mut profiles = self.profiles.borrow_mut();
+        profiles.record_outcome(Player::Player, player_outcome);
+        let ratings = match self.opponent_type {
+            PlayerType::Human => {
+                profiles.record_outcome(Player::Opponent, opponent_outcome);
+                profiles.rate_seats(player_outcome, opponent_outcome)
+            }
+            PlayerType::Ai => profiles.rate_against_ai(self.personality.level, player_outcome, opponent_outcome),
+            _ => None,
+        };
+        //Profiles rate the player first, records keep cross first and the player can be circle
+        record.ratings = ratings.map(|(player, opponent)| {
+            if self.game.player_mark == FieldMark::O {(opponent, player)} else {(player, opponent)}
+        });
+
+        let data_dir = self.settings.borrow().data_dir.clone();
+        let result = profiles.save().and(records::append(&data_dir, &record));
+        drop(profiles);
+        match result {
+            Ok(()) => self.notifications.push(Notification::success(tr("Game saved"))),
+            Err(error) => self.show_error(tr_args("Could not save game: {}", &[&error])),
+        }
+    }
+
+    fn get_banner_ai_game(&self) -> (Banner, Style){
         match self.game.winner {
             None => {
                 match self.game.current_player {
-                    Player::Player => {Art::your_turn().style(self.get_player_style())}
-                    Player::Opponent => {Art::enemy_turn().style(self.get_opponent_style())}
+                    Player::Player => {(Banner::YourTurn, self.get_player_style())}
+                    Player::Opponent => {(Banner::EnemyTurn, self.get_opponent_style())}
                 }
             }
             Some(winner_mark) => {
-                if(winner_mark == self.game.player_mark){
-                    Art::you_won().style(Style::new().green())
-                }else if(winner_mark == self.game.opponent_mark){
-                    Art::you_lost().style(Style::new().red())
+                if winner_mark == self.game.player_mark {
+                    (Banner::YouWon, self.settings.borrow().theme.win_style())
+                }else if winner_mark == self.game.opponent_mark {
+                    (Banner::YouLost, self.settings.borrow().theme.loss_style())
                 }
                 else{
-                    Art::draw()
+                    (Banner::Draw, self.settings.borrow().theme.banner_style())
                 }
             }
         }
     }
 
-    fn get_top_text_human_game(&self) -> Text<'_> {
+    fn get_banner_human_game(&self) -> (Banner, Style) {
         let style = if self.game.current_player == Player::Player {self.get_player_style()} else {self.get_opponent_style()};
 
         match self.game.winner {
             None => {
-                if(self.get_current_mark() == FieldMark::X){
-                    Art::cross_turn().style(style)
+                if self.game.current_mark() == FieldMark::X {
+                    (Banner::CrossTurn, style)
                 }else{
-                    Art::circle_turn().style(style)
+                    (Banner::CircleTurn, style)
                 }
             }
             Some(winner_mark) => {
                 let style = self.get_style_by_mark(winner_mark);
                 match winner_mark {
-                    FieldMark::X => {Art::cross_won().style(style)}
-                    FieldMark::O => {Art::circle_won().style(style)}
-                    FieldMark::Empty => {Art::draw().style(style)}
+                    FieldMark::X => {(Banner::CrossWon, style)}
+                    FieldMark::O => {(Banner::CircleWon, style)}
+                    FieldMark::Empty => {(Banner::Draw, self.settings.borrow().theme.banner_style())}
                 }
             }
         }
     }
 
-    fn get_current_mark(&self) -> FieldMark{
-        if self.game.current_player == Player::Player {self.game.player_mark} else {self.game.opponent_mark}
-    }
-
-    fn draw_board_tiles(&self, frame: &mut Frame, board_tiles: &[Rect]){
+    fn draw_board_tiles(&self, frame: &mut Frame, board_tiles: &[Rect], size: LayoutSize){
         assert_eq!(board_tiles.len(), self.game.board.len());
+        let border_style = self.settings.borrow().theme.border_style();
+        let now = Instant::now();
+        let winning_line = self.game.winning_line().filter(|_| self.game.timed_out.is_none());
 
         for (i, mark) in self.game.board.iter().enumerate(){
             let field = board_tiles[i];
-            let mut block = Block::new().borders(Borders::ALL).border_type(BorderType::Rounded);
+            let mut block = Block::new().borders(Borders::ALL).border_type(BorderType::Rounded).border_style(border_style);
 
-            if(i == self.field_selection as usize && self.game.winner.is_none()){
+            if i == self.field_selection as usize && self.game.winner.is_none() && !self.spectating {
                 block = self.color_board_tile(block);
             }
+            if winning_line.is_some_and(|line| line.contains(&(i as u8))) {
+                block = self.color_winning_tile(block, *mark, now);
+            }
 
-            let mark_art = self.get_field_mark_art(*mark);
+            let mut mark_art = self.get_field_mark_art(*mark, size);
+            if let Some(placed_at) = self.placed_at[i] {
+                mark_art = Self::draw_in(mark_art, Animation::new(placed_at, MARK_DRAW_TIME), now);
+            }
 
             frame.render_widget(Paragraph::new(mark_art).centered().block(block),field);
+            if self.settings.borrow().tile_patterns {
+                self.fill_tile_pattern(frame, field.inner(Margin::new(1,1)), *mark);
+            }
+        }
+
+    }
+
+    /// Fills the blank cells left and right of the mark, hatched for cross and dotted for circle.
+    fn fill_tile_pattern(&self, frame: &mut Frame, area: Rect, mark: FieldMark){
+        let symbol = match mark {
+            FieldMark::X => "╱",
+            FieldMark::O => "·",
+            FieldMark::Empty => return,
+        };
+        let style = self.get_style_by_mark(mark).dim();
+        let buffer = frame.buffer_mut();
+        //Gaps inside the art belong to the mark, only the margins of each row are filled
+        for y in area.top()..area.bottom() {
+            let is_blank = |x: &u16| buffer[(*x, y)].symbol() == " ";
+            let first = (area.left()..area.right()).find(|x| !is_blank(x)).unwrap_or(area.right());
+            let last = (area.left()..area.right()).rev().find(|x| !is_blank(x)).map(|x| x + 1).unwrap_or(area.right());
+            for x in (area.left()..first).chain(last..area.right()) {
+                buffer[(x, y)].set_symbol(symbol).set_style(style);
+            }
+        }
+    }
+
+    /// Reveals the mark row by row from the top, single line marks stay dimmed until they are drawn.
+    fn draw_in(mut mark_art: Text<'static>, animation: Animation, now: Instant) -> Text<'static>{
+        if animation.is_finished(now) {
+            return mark_art;
+        }
+        let height = mark_art.lines.len();
+        if height <= 1 {
+            return mark_art.patch_style(Style::new().dim());
+        }
+        let visible = (height as f64 * animation.progress(now)).ceil() as usize;
+        for line in mark_art.lines.iter_mut().skip(visible) {
+            //Blank rows keep the width, so the centered rows above do not move
+            let width = line.width();
+            *line = Line::from(" ".repeat(width));
+        }
+        mark_art
+    }
+
+    /// Winning tiles flash right after the game ended and keep the winner's color afterwards.
+    fn color_winning_tile<'a>(&self, block: Block<'a>, mark: FieldMark, now: Instant) -> Block<'a>{
+        let style = self.get_style_by_mark(mark);
+        let flashing = self.settings.borrow().animations && self.finished_at
+            .is_some_and(|finished_at| Animation::new(finished_at, WIN_FLASH_TIME).flash(now, WIN_FLASH_PERIOD));
+        if flashing {
+            block.style(style.reversed()).border_style(style)
+        }else{
+            block.border_style(style.bold())
+        }
+    }
+
+    /// Starts animations for marks that appeared and for the end of the game, whoever made the move.
+    fn update_animations(&mut self, now: Instant){
+        let enabled = self.settings.borrow().animations;
+        for index in 0..self.game.board.len() {
+            if self.game.board[index] != self.shown_board[index] {
+                self.shown_board[index] = self.game.board[index];
+                let placed = enabled && self.game.board[index] != FieldMark::Empty;
+                self.placed_at[index] = if placed {Some(now)} else {None};
+            }
+        }
+        match (self.game.winner, self.finished_at) {
+            (Some(_), None) => self.finished_at = Some(now),
+            (None, Some(_)) => self.finished_at = None,
+            _ => {}
+        }
+    }
+
+    /// Describes every new mark and change of the banner in one line, whoever made the move.
+    fn update_announcements(&mut self){
+        let (banner, _) = self.get_banner();
+        let Some((board, announced_banner)) = self.announced else {
+            self.announced = Some((self.game.board, banner));
+            self.announce(tr(banner.as_str()).to_string());
+            return;
+        };
+
+        let mut parts = Vec::new();
+        if (0..9).any(|index| self.game.board[index] == FieldMark::Empty && board[index] != FieldMark::Empty) {
+            parts.push(tr("New game").to_string());
+        }
+        for index in 0..9 {
+            let mark = self.game.board[index];
+            if mark != board[index] && mark != FieldMark::Empty {
+                let name = if mark == FieldMark::X {tr("Cross (X)")} else {tr("Circle (O)")};
+                parts.push(tr_args("{} on {} ({})", &[&name, &tr(TILE_NAMES[index]), &(index + 1)]));
+            }
+        }
+        if banner != announced_banner {
+            parts.push(tr(banner.as_str()).to_string());
+        }
+
+        self.announced = Some((self.game.board, banner));
+        if !parts.is_empty() {
+            self.announce(parts.join(", "));
+        }
+    }
+
+    /// Adds a line for plain text mode, the oldest lines are dropped after `MAX_ANNOUNCEMENTS`.
+    fn announce(&mut self, line: String){
+        self.announcements.push(line);
+        if self.announcements.len() > MAX_ANNOUNCEMENTS {
+            self.announcements.remove(0);
+        }
+    }
 
+    /// Plain text mode: no boxes or art, the board as three lines of text and the announcements below.
+    fn render_plain(&mut self, frame: &mut Frame){
+        let area = frame.area().inner(Margin::new(1,0));
+        let (banner, banner_style) = self.get_banner();
+        let mut lines = vec![Line::styled(tr(banner.as_str()).to_string(), banner_style)];
+        lines.extend(self.get_top_caption().map(Line::from));
+        lines.extend(self.get_opponent_status_line().map(Line::from));
+        if self.opponent_type == PlayerType::Remote {
+            lines.push(Line::from(tr_args("Spectators: {}", &[&self.spectator_count])));
         }
+        lines.push(Line::from(""));
+
+        //Empty tiles show their number, the selected one is in brackets
+        let board_top = area.y + lines.len() as u16;
+        let mut board_tiles = Vec::new();
+        for row in 0..3 {
+            let mut spans = Vec::new();
+            for column in 0..3 {
+                let index = row * 3 + column;
+                let mark = self.game.board[index];
+                let text = if mark == FieldMark::Empty {(index + 1).to_string()} else {mark.as_char().to_ascii_uppercase().to_string()};
+                let selected = index == self.field_selection as usize && self.game.winner.is_none() && !self.spectating;
+                if column > 0 {
+                    spans.push(Span::raw("|"));
+                }
+                spans.push(Span::styled(if selected {format!("[{}]", text)} else {format!(" {} ", text)}, self.get_style_by_mark(mark)));
+                board_tiles.push(Rect { x: area.x + column as u16 * 4, y: board_top + row as u16, width: 3, height: 1 });
+            }
+            lines.push(Line::from(spans));
+        }
+        self.board_tiles = board_tiles.into_iter().filter(|tile| area.contains(tile.as_position())).collect();
+        lines.push(Line::from(""));
 
+        let clock_area = Rect { y: area.y + lines.len() as u16, height: 1, ..area }.intersection(area);
+        if self.clock.is_some() {
+            lines.push(Line::from(""));
+        }
+        let shown = (area.height as usize).saturating_sub(lines.len());
+        let skipped = self.announcements.len().saturating_sub(shown);
+        lines.extend(self.announcements.iter().skip(skipped).map(|announcement| Line::from(announcement.clone())));
+        frame.render_widget(Paragraph::new(lines), area);
+        if self.clock.is_some() {
+            self.draw_clocks(frame, clock_area);
+        }
     }
 
+    /// Progress of the result banner sliding in, 1.0 while the game runs or without animations.
+    fn banner_progress(&self, now: Instant) -> f64{
+        match self.finished_at {
+            Some(finished_at) if self.settings.borrow().animations => Animation::new(finished_at, BANNER_SLIDE_TIME).progress(now),
+            _ => 1.0,
+        }
+    }
+
+    //The border style is replaced too, otherwise the theme border color would hide the selection
     fn color_board_tile<'a>(&self, block: Block<'a>) -> Block<'a>{
-        if(self.opponent_type == PlayerType::Human){
-            if(self.game.current_player == Player::Player){
-                block.style(self.get_player_style())
+        if self.opponent_type == PlayerType::Human {
+            if self.game.current_player == Player::Player {
+                block.style(self.get_player_style()).border_style(self.get_player_style())
             }else{
-                block.style(self.get_opponent_style())
+                block.style(self.get_opponent_style()).border_style(self.get_opponent_style())
             }
         }
-        else if(self.game.current_player == Player::Player){
-            block.style(self.get_player_style())
+        else if self.game.current_player == Player::Player {
+            block.style(self.get_player_style()).border_style(self.get_player_style())
         }
         else{
             block
         }
     }
 
-    fn draw_error_text(&self,text: String){
+    /// Hands the turn over on the clock after a move and ends the game when a side ran out of time.
+    fn update_clock(&mut self, now: Instant){
+        let Some(clock) = self.clock.as_mut() else { return };
+        if self.game.winner.is_some() {
+            clock.stop();
+            return;
+        }
 
+        clock.switch_turn(self.game.current_mark(), now);
+        if let Some(flagged_mark) = clock.tick(now) {
+            self.game.time_out(flagged_mark);
+            clock.stop();
+        }
     }
 
-    fn move_selection_up(&mut self){ if(self.field_selection > 2){self.field_selection -= 3;} }
+    fn draw_clocks(&self, frame: &mut Frame, area: Rect){
+        let Some(clock) = &self.clock else { return };
+        let now = Instant::now();
 
-    fn move_selection_down(&mut self){ if(self.field_selection < 6){self.field_selection += 3;} }
+        let mut spans = Vec::new();
+        for mark in [FieldMark::X, FieldMark::O] {
+            let mut style = self.get_style_by_mark(mark);
+            if clock.turn() == mark && !clock.is_stopped() {
+                style = style.reversed();
+            }
+            let label = match clock.remaining(mark) {
+                Some(remaining) => format!(" {} {} ", mark.as_char().to_ascii_uppercase(), clock::format_duration(remaining)),
+                None => format!(" {} ", mark.as_char().to_ascii_uppercase()),
+            };
+            spans.push(Span::styled(label, style));
+            spans.push(Span::raw("   "));
+        }
+        if let Some(move_remaining) = clock.move_remaining(now) && !clock.is_stopped() {
+            spans.push(Span::raw(tr_args("move: {}", &[&clock::format_duration(move_remaining)])));
+        }
+
+        frame.render_widget(Paragraph::new(Line::from(spans)).centered(), area);
+    }
+
+    /// Rule errors and errors sent by the host are English, they get translated here.
+    fn show_error(&mut self, text: String){
+        self.notifications.push(Notification::error(tr(&text).to_string()));
+    }
+
+    fn move_selection_up(&mut self){ if self.field_selection > 2 {self.field_selection -= 3;} }
+
+    fn move_selection_down(&mut self){ if self.field_selection < 6 {self.field_selection += 3;} }
 
     fn move_selection_left(&mut self){
-        if(self.field_selection > 0){self.field_selection -= 1;}
+        if self.field_selection > 0 {self.field_selection -= 1;}
     }
 
     fn move_selection_right(&mut self){
-        if(self.field_selection < 8){self.field_selection += 1;}
+        if self.field_selection < 8 {self.field_selection += 1;}
     }
 
     fn player_make_move(&mut self){
+        if let Some(RemoteSession::Client(peer)) = self.remote.as_mut() {
+            if let Err(error) = peer.send(&Message::Move(self.field_selection)) {
+                self.report_opponent_error(error);
+            }
+            return;
+        }
+
         if self.field_selection < 9 && let Err(message) = self.game.make_move(self.field_selection){
-            self.draw_error_text(message);
+            self.show_error(message);
         }
+        self.update_clock(Instant::now());
+        self.broadcast_to_remote(self.remote_state_message());
     }
 
-    fn ai_make_move(&mut self){
-        if(self.game.winner.is_none() && self.game.current_player == Player::Opponent && self.ai_thinking_gauge == 100){
-            let ai_move = self.game.get_ai_move(Player::Opponent);
-            match ai_move {
-                None => {}
-                Some(index) => {
-                    match self.game.make_move(index){
-                        Ok(_) => {}
-                        Err(message) => {self.draw_error_text(message);}
+    fn remote_state_message(&self) -> Message{
+        Message::State {
+            board: self.game.board,
+            to_move: self.game.current_mark(),
+            winner: self.game.winner,
+        }
+    }
+
+    /// Host only, pushes the message (usually the authoritative game state) to the player and spectators.
+    fn broadcast_to_remote(&mut self, message: Message){
+        if let Some(RemoteSession::Host(session)) = self.remote.as_mut()
+            && let Err(error) = session.broadcast(&message) {
+            self.report_opponent_error(error);
+        }
+    }
+
+    fn is_remote_ready(&self) -> bool{
+        match &self.remote {
+            Some(RemoteSession::Host(session)) => session.opponent.is_some(),
+            Some(RemoteSession::Client(_)) => true,
+            None => false,
+        }
+    }
+
+    fn remote_make_move(&mut self){
+        let result = match self.remote {
+            Some(RemoteSession::Host(_)) => self.update_remote_host(),
+            Some(RemoteSession::Client(_)) => self.update_remote_client(),
+            None => Ok(()),
+        };
+        if let Err(error) = result {
+            self.report_opponent_error(error);
+        }
+    }
+
+    fn update_remote_host(&mut self) -> Result<(), NetworkError>{
+        let Some(RemoteSession::Host(session)) = self.remote.as_mut() else { return Ok(()) };
+        if session.update_connections()? {
+            self.spectator_count = session.spectators.len();
+            self.broadcast_to_remote(Message::Spectators(self.spectator_count));
+            self.broadcast_to_remote(self.remote_state_message());
+        }
+
+        loop {
+            let Some(RemoteSession::Host(session)) = self.remote.as_mut() else { return Ok(()) };
+            let Some(peer) = session.opponent.as_mut() else { return Ok(()) };
+            let Some(message) = peer.poll()? else { return Ok(()) };
+
+            match message {
+                Message::Move(index) => {
+                    let result = if self.game.winner.is_some() || self.game.current_player != Player::Opponent {
+                        Err("Not your turn".to_string())
+                    }else{
+                        self.game.make_move(index)
+                    };
+                    if let Err(message) = result {
+                        peer.send(&Message::Error(message))?;
                     }
+                    self.broadcast_to_remote(self.remote_state_message());
                 }
+                Message::Bye => return Err(NetworkError::Disconnected),
+                other => return Err(NetworkError::Protocol(other.encode())),
             }
-            self.ai_thinking_gauge = 0;
         }
     }
 
-    fn opponent_make_move(&mut self){
-        self.ai_make_move();
+    fn update_remote_client(&mut self) -> Result<(), NetworkError>{
+        loop {
+            let Some(RemoteSession::Client(peer)) = self.remote.as_mut() else { return Ok(()) };
+            let Some(message) = peer.poll()? else { return Ok(()) };
+
+            match message {
+                Message::Welcome(Role::Player) => {}
+                Message::Welcome(Role::Spectator) => {
+                    //The host may seat us as a spectator when the game is already full
+                    self.spectating = true;
+                    self.game = Game::new();
+                    self.notifications.push(Notification::info(tr("The game is full, you are spectating")));
+                }
+                Message::Reject(reason) => return Err(NetworkError::Rejected(reason)),
+                Message::State { board, to_move, winner } => {self.game.apply_state(board, to_move, winner)}
+                Message::Spectators(count) => {self.spectator_count = count}
+                Message::Error(message) => {self.show_error(message)}
+                Message::Bye => return Err(NetworkError::Disconnected),
+                other => return Err(NetworkError::Protocol(other.encode())),
+            }
+        }
     }
 
+    /// Starts the search on the AI turn and plays its move once the worker is done, without blocking.
+    fn ai_make_move(&mut self){
+        if self.game.winner.is_some() || self.game.current_player != Player::Opponent {
+            //A game that ended on time leaves a running search behind
+            self.ai_worker = None;
+            return;
+        }
+        let think_time = self.settings.borrow().ai_think_time;
+        let worker = self.ai_worker.get_or_insert_with(|| AiWorker::start(&self.game, Player::Opponent, self.personality.level, think_time));
+
+        match worker.poll_move() {
+            Ok(Some(index)) => {
+                self.ai_worker = None;
+                if let Err(message) = self.game.make_move(index) {
+                    self.show_error(message);
+                }
+            }
+            Ok(None) => {}
+            Err(message) => {
+                self.ai_worker = None;
+                self.report_opponent_error(message);
+            }
+        }
+    }
 
-    fn handle_input_your_turn(&mut self, key: KeyEvent) -> ViewAction{
-        match key.code {
-            KeyCode::Esc => return ViewAction::GoToMain,
-            KeyCode::Up => {self.move_selection_up();}
-            KeyCode::Down => {self.move_selection_down();}
-            KeyCode::Left => {self.move_selection_left();}
-            KeyCode::Right => {self.move_selection_right();}
-            KeyCode::Enter => {self.player_make_move();}
-            _ => {}
+    fn engine_make_move(&mut self){
+        self.poll_engine_launcher();
+        if self.game.winner.is_some() || self.game.current_player != Player::Opponent {
+            return;
+        }
+        let Some(engine) = self.engine.as_mut() else { return };
+
+        let result = if engine.is_thinking() {
+            engine.poll_move(&self.game)
+        }else{
+            engine.request_move(&self.game).map(|_| None)
+        };
+
+        match result {
+            Ok(Some(index)) => {
+                if let Err(message) = self.game.make_move(index) {
+                    self.show_error(message);
+                }
+            }
+            Ok(None) => {}
+            Err(error) => {self.report_opponent_error(error)}
         }
-        ViewAction::Nothing
     }
 
-    fn handle_input_enemy_turn(&mut self, key: KeyEvent) -> ViewAction{
-        match key.code {
-            KeyCode::Esc => return ViewAction::GoToMain,
-            _ => {}
+    fn opponent_make_move(&mut self){
+        match self.opponent_type {
+            PlayerType::Ai => {self.ai_make_move()}
+            PlayerType::Engine => {self.engine_make_move()}
+            PlayerType::Remote => {self.remote_make_move()}
+            PlayerType::Human => {}
         }
+    }
 
-        ViewAction::Nothing
+
+    fn get_key_action(&self, key: KeyEvent) -> Option<KeyAction>{
+        self.settings.borrow().keymap.action_for(key)
     }
 
-    fn handle_input_end(&mut self, key: KeyEvent) -> ViewAction{
-        match key.code {
-            KeyCode::Esc => return ViewAction::GoToMain,
-            _ => {}
+    /// Keys while the local side is to move, the Controls panel shows them during the whole game.
+    fn move_bindings() -> Vec<Binding<AiGameView>>{
+        vec![
+            Binding::action(KeyAction::Left, "Select tile", |view, _| {view.move_selection_left(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Up, "Select tile", |view, _| {view.move_selection_up(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Down, "Select tile", |view, _| {view.move_selection_down(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Right, "Select tile", |view, _| {view.move_selection_right(); ViewAction::Nothing}),
+            Binding::tiles("Place on tile", |view, key| {
+                if let Some(KeyAction::Tile(index)) = view.get_key_action(key) {
+                    view.field_selection = index;
+                    view.player_make_move();
+                }
+                ViewAction::Nothing
+            }),
+            Binding::action(KeyAction::Confirm, "Place mark", |view, _| {view.player_make_move(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Back, "Pause menu", |view, _| view.pause()),
+            Binding::help(),
+        ]
+    }
+
+    fn bindings(&self) -> Vec<Binding<AiGameView>>{
+        if self.game.winner.is_some() {
+            vec![
+                Binding::action(KeyAction::Back, "Back to menu", |_, _| ViewAction::GoToMain),
+                Binding::help(),
+            ]
+        }else if self.is_local_turn() {
+            Self::move_bindings()
+        }else{
+            vec![
+                Binding::action(KeyAction::Back, "Pause menu", |view, _| view.pause()),
+                Binding::help(),
+            ]
         }
+    }
 
-        ViewAction::Nothing
+    /// True when the local side may select and place a mark right now.
+    fn is_local_turn(&self) -> bool{
+        let remote_waiting = self.opponent_type == PlayerType::Remote && (self.spectating || !self.is_remote_ready());
+        if self.game.winner.is_some() || remote_waiting {
+            return false;
+        }
+        self.game.current_player == Player::Player || self.opponent_type == PlayerType::Human
     }
 
 }
 impl ViewModel for AiGameView{
     fn render_widgets(&mut self, frame: &mut Frame) {
-        let main_layout = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([
-                Constraint::Percentage(75),
-                Constraint::Percentage(25),
-            ])
-            .split(frame.area());
+        if self.settings.borrow().plain_text {
+            self.render_plain(frame);
+            return;
+        }
+        let size = LayoutSize::for_area(frame.area());
+        let show_side_panels = frame.area().width >= SIDE_PANEL_MIN_WIDTH;
+
+        let (left_area, right_area) = if show_side_panels {
+            let main_layout = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Percentage(75),
+                    Constraint::Percentage(25),
+                ])
+                .split(frame.area());
+            (main_layout[0], Some(main_layout[1]))
+        }else{
+            (frame.area(), None)
+        };
 
-        let left_area = main_layout[0];
-        let right_area = main_layout[1];
+        let (board_height, board_width) = Self::get_board_size(size);
         let left_area_rects = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
                 Constraint::Fill(1),
-                Constraint::Length(18),
-                Constraint::Length(2),
+                Constraint::Length(board_height),
+                Constraint::Length(if show_side_panels {2} else {3}),
             ])
             .split(left_area);
 
         let board_area = left_area_rects[1]
-            .centered_horizontally(Constraint::Length(33));
+            .centered_horizontally(Constraint::Length(board_width));
 
 
         //Drawing left/right separation
-        frame.render_widget(Block::new().borders(Borders::ALL).border_type(BorderType::Rounded).title("Game"), left_area);
+        let border_style = self.settings.borrow().theme.border_style();
+        frame.render_widget(Block::new().borders(Borders::ALL).border_type(BorderType::Rounded).border_style(border_style).title(tr("Game")), left_area);
 
-        let separated_right_area = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-            .split(right_area);
-        let right_top = separated_right_area[0];
-        let right_bottom = separated_right_area[1];
-
-        frame.render_widget(Block::new().borders(Borders::ALL).border_type(BorderType::Rounded).title("AI Status").title_alignment(Alignment::Center), right_top);
-        frame.render_widget(Block::new().borders(Borders::ALL).border_type(BorderType::Rounded).title("Controls").title_alignment(Alignment::Center), right_bottom);
-
-        //Drawing ai status
-        self.draw_ai_status(frame,right_top);
-
-        //Drawing right screen controls
-        let controls_rect = right_bottom.inner(Margin::new(2,2));
-
-        let controls_paragraph = Paragraph::new(Self::get_controls_text()).wrap(Wrap { trim: true });
-        frame.render_widget(controls_paragraph.centered(), controls_rect);
+        if let Some(right_area) = right_area {
+            self.draw_side_panels(frame, right_area);
+        }
 
         //Drawing text above board
-        let text = self.get_top_text();
-        let above_board_area = left_area_rects[0]
-            .inner(Margin::new(0,2))
-            .centered_horizontally(Constraint::Length(text.width() as u16));
-        frame.render_widget(text, above_board_area);
+        let banner_progress = self.banner_progress(Instant::now());
+        let (banner, banner_style) = self.get_banner();
+        let art = Art::banner(banner);
+        //Some translated banners are wider than the board pane, those fall back to the single line too
+        if size == LayoutSize::Compact || art.width() as u16 + 2 > left_area.width {
+            let mut lines = vec![Line::styled(tr(banner.as_str()).to_string(), banner_style)];
+            lines.extend(self.get_top_caption().map(Line::from));
+            let lines_height = lines.len() as u16;
+            let top_area = left_area_rects[0].inner(Margin::new(1,0));
+            let text_area = Rect { y: top_area.y + 1, height: top_area.height.saturating_sub(1), ..top_area }
+                .centered_vertically(Constraint::Length(lines_height));
+            let text_area = animation::slide_in(text_area, top_area, banner_progress);
+            frame.render_widget(Paragraph::new(lines).centered(), text_area);
+        }else{
+            let text = art.style(banner_style);
+            let text_height = text.height() as u16;
+            let above_board_area = left_area_rects[0]
+                .inner(Margin::new(0,2))
+                .centered_horizontally(Constraint::Length(text.width() as u16));
+            let banner_area = animation::slide_in(above_board_area, left_area.inner(Margin::new(1,0)), banner_progress);
+            frame.render_widget(text, banner_area);
+
+            if let Some(caption) = self.get_top_caption() && text_height < above_board_area.height {
+                let caption_area = Rect { y: above_board_area.y + text_height, height: 1, ..left_area_rects[0].inner(Margin::new(1,0)) };
+                frame.render_widget(Paragraph::new(caption).centered(), caption_area);
+            }
+        }
 
         //Drawing board tiles
         let board_tiles = AiGameView::get_board_tiles(board_area);
-        self.draw_board_tiles(frame, &board_tiles);
+        self.draw_board_tiles(frame, &board_tiles, size);
+        self.board_tiles = board_tiles;
+
+        //Drawing opponent status, clocks or spectators below board
+        let mut below_board_area = left_area_rects[2].inner(Margin::new(1,0));
+        if !show_side_panels {
+            if let Some(status) = self.get_opponent_status_line() {
+                let style = if self.opponent_error.is_some() {self.settings.borrow().theme.loss_style()} else {self.get_opponent_style()};
+                frame.render_widget(Paragraph::new(status).centered().style(style), Rect { height: 1, ..below_board_area });
+            }
+            below_board_area.y += 1;
+        }
+        if self.clock.is_some() {
+            self.draw_clocks(frame, Rect { height: 1, ..below_board_area });
+        }
+        else if self.opponent_type == PlayerType::Remote {
+            let spectators_text = tr_args("Spectators: {}", &[&self.spectator_count]);
+            frame.render_widget(Paragraph::new(spectators_text).centered(), Rect { height: 1, ..below_board_area });
+        }
 
     }
     fn handle_inputs(&mut self, key: KeyEvent) -> ViewAction {
-        if(self.game.winner.is_some()){
-            self.handle_input_end(key)
-        }else if(self.game.current_player == Player::Player){
-            self.handle_input_your_turn(key)
-        }else {
-            if(self.opponent_type == PlayerType::Ai){
-                self.handle_input_enemy_turn(key)
-            }else{
-                self.handle_input_your_turn(key)
-            }
+        let action = self.get_key_action(key);
+        let bindings = self.bindings();
+        key_table::dispatch(self, &bindings, action, key).unwrap_or(ViewAction::Nothing)
+    }
+
+    fn help(&self) -> Vec<HelpEntry> {
+        key_table::help_entries(&self.bindings(), &self.settings.borrow().keymap)
+    }
 
+    fn update(&mut self, now: Instant) -> ViewAction {
+        self.opponent_make_move();
+        self.update_clock(now);
+        self.record_finished_game();
+        self.update_animations(now);
+        self.update_announcements();
+        self.update_ai_line();
+        ViewAction::Nothing
+    }
+
+    //Behind the pause menu, help and settings only network games go on, the other side does not pause and
+    //they have no clock. Moves of the AI and engines wait in their channels until the game is on top again
+    fn background_update(&mut self, now: Instant){
+        if self.opponent_type == PlayerType::Remote {
+            self.update(now);
         }
+    }
+
+    fn handle_result(&mut self, result: DialogResult) -> ViewAction {
+        match result {
+            DialogResult::PauseMenu(PauseOption::Restart) if self.is_in_progress() => return ViewAction::OpenConfirm(Confirmation::RestartGame),
+            DialogResult::PauseMenu(PauseOption::Restart) | DialogResult::Confirmed(Confirmation::RestartGame) => {self.restart()}
+            DialogResult::PauseMenu(PauseOption::Save) => {self.save()}
+            DialogResult::PauseMenu(PauseOption::Settings) => return ViewAction::GoToSettings,
+            DialogResult::PauseMenu(PauseOption::QuitToMenu) if self.is_in_progress() => return ViewAction::OpenConfirm(Confirmation::LeaveGame),
+            DialogResult::PauseMenu(PauseOption::QuitToMenu) | DialogResult::Confirmed(Confirmation::LeaveGame) => return ViewAction::GoToMain,
+            DialogResult::PauseMenu(PauseOption::Resume) | DialogResult::Confirmed(Confirmation::ResetSettings) | DialogResult::Closed => {}
+        }
+        self.resume();
+        ViewAction::Nothing
+    }
+
+    fn take_notifications(&mut self) -> Vec<Notification>{
+        std::mem::take(&mut self.notifications)
+    }
+
+    fn describe_state(&self) -> String {
+        let clock = self.clock.as_ref().map(|clock| format!("{:?} {:?} / {:?}",
+            clock.turn(), clock.remaining(FieldMark::X), clock.remaining(FieldMark::O)));
+        format!(
+            "opponent: {:?}\npersonality: {} ({})\nboard: {}\nmoves: {:?}\nto move: {:?}\nwinner: {:?}\ntimed out: {:?}\nclock: {:?}\nspectating: {}\nai thinking: {}\nopponent error: {:?}",
+            self.opponent_type, self.personality.name, self.personality.level.as_str(), game::encode_board(&self.game.board), self.game.moves,
+            self.game.current_mark(), self.game.winner, self.game.timed_out, clock, self.spectating,
+            self.ai_worker.is_some(), self.opponent_error,
+        )
+    }
 
+    //Moves and messages of the opponent arrive as AppEvent::Background, ticks only redraw what moves by itself
+    fn is_live(&self) -> bool {
+        let now = Instant::now();
+        let clock_running = self.clock.as_ref().is_some_and(|clock| !clock.is_stopped());
+        let animating = self.settings.borrow().animations && (
+            self.placed_at.iter().flatten().any(|placed_at| !Animation::new(*placed_at, MARK_DRAW_TIME).is_finished(now))
+            || self.finished_at.is_some_and(|finished_at| !Animation::new(finished_at, WIN_FLASH_TIME).is_finished(now))
+            || self.banner_progress(now) < 1.0);
+        //Progress bars and timeouts of the opponent
+        let opponent_pending = self.ai_worker.is_some()
+            || self.engine_launcher.is_some()
+            || self.engine.as_ref().is_some_and(Engine::is_thinking);
+        //The host accepts players and spectators on ticks, without a thread to wake the loop
+        let remote_active = self.remote.is_some() && self.game.winner.is_none();
+        let blink_due = self.opponent_type == PlayerType::Ai && self.is_blinking(now) != self.drawn_blinking;
+        clock_running || animating || opponent_pending || remote_active || blink_due
     }
 
-    fn additional_actions(&mut self) {
-        if(self.opponent_type == PlayerType::Ai)
-        {
-            self.opponent_make_move();
+    fn handle_mouse(&mut self, event: MouseEvent) -> ViewAction {
+        if !self.is_local_turn() {
+            return ViewAction::Nothing;
         }
+        let Some(index) = mouse::rect_at(&self.board_tiles, event.column, event.row) else {
+            return ViewAction::Nothing
+        };
+        match event.kind {
+            MouseEventKind::Moved => self.field_selection = index as u8,
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.field_selection = index as u8;
+                self.player_make_move();
+            }
+            _ => {}
+        }
+        ViewAction::Nothing
     }
+
 }
 
 
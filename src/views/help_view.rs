@@ -0,0 +1,85 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use ratatui::crossterm::event::KeyEvent;
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Constraint};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, BorderType, Clear, List, ListState};
+use crate::enums::key_action::KeyAction;
+use crate::enums::view_action::ViewAction;
+use crate::helpers::key_table;
+use crate::helpers::key_table::{Binding, HelpEntry};
+use crate::services::locale::{tr, tr_args};
+use crate::services::settings::Settings;
+use crate::traits::view_model::ViewModel;
+
+/// Overlay listing the keys of the view below it, the entries come from that view's key table.
+pub struct HelpView{
+    entries: Vec<HelpEntry>,
+    list: ListState,
+    settings: Rc<RefCell<Settings>>,
+}
+
+impl HelpView{
+    pub fn new(entries: Vec<HelpEntry>, settings: Rc<RefCell<Settings>>) -> HelpView{
+        let mut list = ListState::default();
+        list.select(Some(0));
+
+        HelpView{
+            entries,
+            list,
+            settings,
+        }
+    }
+
+    fn bindings() -> Vec<Binding<HelpView>>{
+        vec![
+            Binding::action(KeyAction::Up, "Scroll", |view, _| {view.list.select_previous(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Down, "Scroll", |view, _| {view.list.select_next(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Back, "Close", |_, _| ViewAction::Back),
+            Binding::action(KeyAction::Confirm, "Close", |_, _| ViewAction::Back),
+            Binding::action(KeyAction::Help, "Close", |_, _| ViewAction::Back),
+        ]
+    }
+}
+
+impl ViewModel for HelpView{
+    fn render_widgets(&mut self, frame: &mut Frame){
+        let theme = self.settings.borrow().theme.clone();
+        let keys_width = self.entries.iter().map(|entry| entry.keys.chars().count()).max().unwrap_or(0);
+        let text_width = self.entries.iter().map(|entry| keys_width + 3 + entry.description.chars().count()).max().unwrap_or(0);
+        let popup_area = frame.area().centered(Constraint::Length(text_width.max(30) as u16 + 4),Constraint::Length(self.entries.len() as u16 + 2));
+
+        let keymap = &self.settings.borrow().keymap;
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .style(theme.base_style())
+            .border_style(theme.border_style())
+            .title(tr("Keys"))
+            .title_bottom(tr_args("{} - close", &[&keymap.label_for(KeyAction::Back)]))
+            .title_alignment(Alignment::Center);
+        frame.render_widget(Clear, popup_area);
+
+        let items = self.entries.iter().map(|entry| Line::from(vec![
+            Span::styled(format!(" {:>width$}", entry.keys, width = keys_width), theme.accent_style()),
+            Span::raw(format!("  {}", entry.description)),
+        ])).collect::<Vec<Line>>();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(theme.highlight_style());
+        frame.render_stateful_widget(list, popup_area, &mut self.list);
+    }
+
+    fn handle_inputs(&mut self, key: KeyEvent) -> ViewAction{
+        let action = self.settings.borrow().keymap.action_for(key);
+        key_table::dispatch(self, &Self::bindings(), action, key).unwrap_or(ViewAction::Nothing)
+    }
+
+    fn help(&self) -> Vec<HelpEntry>{
+        key_table::help_entries(&Self::bindings(), &self.settings.borrow().keymap)
+    }
+
+    fn is_overlay(&self) -> bool{
+        true
+    }
+}
@@ -0,0 +1,108 @@
+use std::cell::RefCell;
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::rc::Rc;
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::Style;
+use ratatui::widgets::{Block, BorderType, Borders, Paragraph};
+use crate::enums::view_action::ViewAction;
+use crate::helpers::key_table;
+use crate::helpers::key_table::{Binding, HelpEntry};
+use crate::helpers::text_input::TextInput;
+use crate::services::locale::{tr, tr_args};
+use crate::services::network::Role;
+use crate::services::settings::Settings;
+use crate::traits::view_model::ViewModel;
+use crate::services::game_art as Art;
+
+pub struct JoinView{
+    address_input: TextInput,
+    error: Option<String>,
+    settings: Rc<RefCell<Settings>>,
+}
+
+impl JoinView{
+    pub fn new(settings: Rc<RefCell<Settings>>) -> JoinView{
+        let port = settings.borrow().network_port;
+        JoinView{
+            address_input: TextInput::new(&format!("127.0.0.1:{}", port), 64),
+            error: None,
+            settings,
+        }
+    }
+
+    fn parse_address(&self) -> Result<SocketAddr, String>{
+        let text = self.address_input.value().trim();
+        text.to_socket_addrs()
+            .ok()
+            .and_then(|mut addresses| addresses.next())
+            .ok_or(tr_args("Invalid address: {}", &[&text]))
+    }
+
+    fn join(&mut self) -> ViewAction{
+        match self.parse_address() {
+            Ok(address) => ViewAction::JoinGame(address, Role::Player),
+            Err(message) => {
+                self.error = Some(message);
+                ViewAction::Nothing
+            }
+        }
+    }
+
+    fn bindings(&self) -> Vec<Binding<JoinView>>{
+        vec![
+            Binding::key(KeyCode::Enter, "Join", |view, _| view.join()),
+            Binding::key(KeyCode::Esc, "Back", |_, _| ViewAction::Back),
+            Binding::typing_help(),
+        ]
+    }
+}
+
+impl ViewModel for JoinView{
+    fn render_widgets(&mut self, frame: &mut Frame){
+        let title = Art::title(Art::join(), "Join", self.settings.borrow().plain_text);
+
+        let main_layout_rects = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(50),
+                Constraint::Length(3),
+                Constraint::Length(2),
+                Constraint::Fill(1),
+            ])
+            .split(frame.area());
+
+        let title_area = main_layout_rects[0]
+            .centered(Constraint::Length(title.width() as u16),Constraint::Percentage(50));
+        frame.render_widget(title, title_area);
+
+        let input_area = main_layout_rects[1].centered_horizontally(Constraint::Percentage(50));
+        self.address_input.render(frame, input_area, tr("Host address"));
+
+        if let Some(error) = &self.error {
+            frame.render_widget(Paragraph::new(error.as_str()).centered().style(Style::new().red()), main_layout_rects[2]);
+        }
+
+        let help = Paragraph::new(tr("Enter - join game, ESC - go back")).centered();
+        frame.render_widget(help, main_layout_rects[3].centered_vertically(Constraint::Length(1)));
+
+        frame.render_widget(Block::new().borders(Borders::ALL).border_type(BorderType::Rounded), frame.area());
+    }
+
+    //Keys without a binding are typed into the address
+    fn handle_inputs(&mut self, key: KeyEvent) -> ViewAction {
+        let bindings = self.bindings();
+        if let Some(view_action) = key_table::dispatch(self, &bindings, None, key) {
+            return view_action;
+        }
+        if self.address_input.handle_key(key) {
+            self.error = None;
+        }
+        ViewAction::Nothing
+    }
+
+    fn help(&self) -> Vec<HelpEntry> {
+        key_table::help_entries(&self.bindings(), &self.settings.borrow().keymap)
+    }
+}
@@ -0,0 +1,136 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use ratatui::crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Constraint, Direction, Layout, Margin, Rect};
+use ratatui::widgets::{Block, Clear, List, ListState, Paragraph};
+use crate::enums::key_action::KeyAction;
+use crate::enums::view_action::ViewAction;
+use crate::helpers::key_table;
+use crate::helpers::key_table::{Binding, HelpEntry};
+use crate::helpers::mouse;
+use crate::helpers::notifications::Notification;
+use crate::services::keymap;
+use crate::services::locale::{tr, tr_args};
+use crate::services::settings::Settings;
+use crate::traits::view_model::ViewModel;
+
+/// Overlay for rebinding single actions, Confirm waits for the next key and binds it.
+pub struct KeyBindingsView{
+    key_list: ListState,
+    key_list_area: Rect,
+    capturing_key: Option<KeyAction>,
+    settings: Rc<RefCell<Settings>>,
+    notifications: Vec<Notification>,
+}
+
+impl KeyBindingsView{
+    pub fn new(settings: Rc<RefCell<Settings>>) -> KeyBindingsView{
+        let mut key_list = ListState::default();
+        key_list.select(Some(0));
+
+        KeyBindingsView{
+            key_list,
+            key_list_area: Rect::default(),
+            capturing_key: None,
+            settings,
+            notifications: Vec::new(),
+        }
+    }
+
+    fn key_option_text(&self, action: KeyAction) -> String{
+        let keymap = &self.settings.borrow().keymap;
+        let keys = keymap.keys_for(action).into_iter().map(keymap::key_label).collect::<Vec<String>>();
+        if self.capturing_key == Some(action) {
+            tr_args("{}: press a key...", &[&action.description()])
+        }else{
+            format!("{}: {}", action.description(), keys.join(", "))
+        }
+    }
+
+    fn get_selected_key_action(&self) -> Option<KeyAction>{
+        self.key_list.selected().and_then(|index| KeyAction::get_rebindable().get(index).copied())
+    }
+
+    fn bindings(&self) -> Vec<Binding<KeyBindingsView>>{
+        vec![
+            Binding::action(KeyAction::Up, "Select", |view, _| {view.key_list.select_previous(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Down, "Select", |view, _| {view.key_list.select_next(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Confirm, "Rebind", |view, _| {view.capturing_key = view.get_selected_key_action(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Back, "Close", |_, _| ViewAction::Back),
+            Binding::help(),
+        ]
+    }
+}
+
+impl ViewModel for KeyBindingsView{
+    fn render_widgets(&mut self, frame: &mut Frame){
+        let theme = self.settings.borrow().theme.clone();
+        let popup_area = frame.area().centered(Constraint::Percentage(50),Constraint::Percentage(50));
+        let popup_area_rects = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Fill(1),Constraint::Length(2)])
+            .split(popup_area.inner(Margin::new(1,1)));
+
+        let block = Block::bordered().style(theme.base_style()).border_style(theme.border_style()).title(tr("Rebind keys")).title_alignment(Alignment::Center);
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(block, popup_area);
+
+        let list_items = KeyAction::get_rebindable().into_iter().map(|action| self.key_option_text(action)).collect::<Vec<String>>();
+        let list = List::new(list_items)
+            .highlight_style(theme.highlight_style())
+            .highlight_symbol(">>")
+            .repeat_highlight_symbol(true);
+        frame.render_stateful_widget(list, popup_area_rects[0], &mut self.key_list);
+        self.key_list_area = popup_area_rects[0];
+
+        let keymap = &self.settings.borrow().keymap;
+        let help_text = tr_args("{} - rebind, {} - close", &[&keymap.label_for(KeyAction::Confirm), &keymap.label_for(KeyAction::Back)]);
+        frame.render_widget(Paragraph::new(help_text).centered(), popup_area_rects[1]);
+    }
+
+    fn handle_inputs(&mut self, key: KeyEvent) -> ViewAction{
+        //While capturing every key is accepted, also the ones bound to other actions
+        if let Some(action) = self.capturing_key.take() {
+            if let Err(error) = self.settings.borrow_mut().change_keymap(|keymap| keymap.rebind(action, key.code)) {
+                self.notifications.push(Notification::error(tr_args("Could not save key bindings: {}", &[&error])));
+            }
+            return ViewAction::Nothing;
+        }
+
+        let action = self.settings.borrow().keymap.action_for(key);
+        let bindings = self.bindings();
+        key_table::dispatch(self, &bindings, action, key).unwrap_or(ViewAction::Nothing)
+    }
+
+    fn help(&self) -> Vec<HelpEntry>{
+        key_table::help_entries(&self.bindings(), &self.settings.borrow().keymap)
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) -> ViewAction{
+        let item_count = KeyAction::get_rebindable().len();
+        let Some(index) = mouse::list_item_at(self.key_list_area, &self.key_list, item_count, event.column, event.row) else {
+            return ViewAction::Nothing
+        };
+        if self.capturing_key.is_some() {
+            return ViewAction::Nothing;
+        }
+        match event.kind {
+            MouseEventKind::Moved => self.key_list.select(Some(index)),
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.key_list.select(Some(index));
+                self.capturing_key = self.get_selected_key_action();
+            }
+            _ => {}
+        }
+        ViewAction::Nothing
+    }
+
+    fn is_overlay(&self) -> bool{
+        true
+    }
+
+    fn take_notifications(&mut self) -> Vec<Notification>{
+        std::mem::take(&mut self.notifications)
+    }
+}
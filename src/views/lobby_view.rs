@@ -0,0 +1,144 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Instant;
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::Style;
+use ratatui::widgets::{Block, BorderType, Borders, List, ListState, Paragraph};
+use crate::enums::key_action::KeyAction;
+use crate::enums::view_action::ViewAction;
+use crate::helpers::key_table;
+use crate::helpers::key_table::{Binding, HelpEntry};
+use crate::services::discovery::{DiscoveredGame, DiscoveryClient};
+use crate::services::locale::{tr, tr_args};
+use crate::services::network::Role;
+use crate::services::settings::Settings;
+use crate::traits::view_model::ViewModel;
+use crate::services::game_art as Art;
+
+pub struct LobbyView{
+    discovery: Option<DiscoveryClient>,
+    games_list: ListState,
+    error: Option<String>,
+    settings: Rc<RefCell<Settings>>,
+}
+
+impl LobbyView{
+    pub fn new(settings: Rc<RefCell<Settings>>) -> LobbyView{
+        let mut list_state = ListState::default();
+        list_state.select(Some(0));
+
+        let discovery_port = settings.borrow().discovery_port;
+        let (discovery, error) = match DiscoveryClient::new(discovery_port) {
+            Ok(client) => (Some(client), None),
+            Err(error) => (None, Some(error.to_string())),
+        };
+
+        LobbyView{
+            discovery,
+            games_list: list_state,
+            error,
+            settings,
+        }
+    }
+
+    fn get_games(&self) -> &[DiscoveredGame]{
+        match &self.discovery {
+            Some(discovery) => discovery.games(),
+            None => &[],
+        }
+    }
+
+    fn join_selected_game(&self, role: Role) -> ViewAction{
+        let index = self.games_list.selected().unwrap_or(0);
+        match self.get_games().get(index) {
+            Some(game) => ViewAction::JoinGame(game.address, role),
+            None => ViewAction::Nothing,
+        }
+    }
+
+    fn format_game(game: &DiscoveredGame) -> String{
+        let status = tr(if game.open {"open"} else {"full"});
+        format!("{} - {} - {} ({})", game.host_name, game.variant, game.address, status)
+    }
+
+    //The letters come after the actions, so with WASD `s` still moves down and `S` spectates
+    fn bindings(&self) -> Vec<Binding<LobbyView>>{
+        vec![
+            Binding::action(KeyAction::Up, "Select game", |view, _| {view.games_list.select_previous(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Down, "Select game", |view, _| {view.games_list.select_next(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Confirm, "Join", |view, _| view.join_selected_game(Role::Player)),
+            Binding::key(KeyCode::Char('s'), "Spectate", |view, _| view.join_selected_game(Role::Spectator)),
+            Binding::key(KeyCode::Char('a'), "Type address", |_, _| ViewAction::GoToJoin),
+            Binding::action(KeyAction::Back, "Back to menu", |_, _| ViewAction::Back),
+            Binding::help(),
+        ]
+    }
+}
+
+impl ViewModel for LobbyView{
+    fn render_widgets(&mut self, frame: &mut Frame){
+        let title = Art::title(Art::lobby(), "Lobby", self.settings.borrow().plain_text);
+
+        let main_layout_rects = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(40),
+                Constraint::Fill(1),
+                Constraint::Length(2),
+            ])
+            .split(frame.area());
+
+        let title_area = main_layout_rects[0]
+            .centered(Constraint::Length(title.width() as u16),Constraint::Percentage(50));
+        frame.render_widget(title, title_area);
+
+        let list_area = main_layout_rects[1].centered_horizontally(Constraint::Percentage(75));
+        if let Some(error) = &self.error {
+            frame.render_widget(Paragraph::new(error.as_str()).centered().style(Style::new().red()), list_area);
+        }
+        else if self.get_games().is_empty() {
+            frame.render_widget(Paragraph::new(tr("Searching for games on the local network...")).centered(), list_area);
+        }
+        else{
+            let list_items = self.get_games().iter().map(Self::format_game).collect::<Vec<String>>();
+            let list = List::new(list_items)
+                .highlight_style(Style::new().reversed())
+                .highlight_symbol(">>")
+                .repeat_highlight_symbol(true);
+            frame.render_stateful_widget(list, list_area, &mut self.games_list);
+        }
+
+        let keymap = &self.settings.borrow().keymap;
+        let help_text = tr_args("{} - join game, S - spectate, A - type address, {} - go back",
+            &[&keymap.label_for(KeyAction::Confirm), &keymap.label_for(KeyAction::Back)]);
+        let help = Paragraph::new(help_text).centered();
+        frame.render_widget(help, main_layout_rects[2]);
+
+        frame.render_widget(Block::new().borders(Borders::ALL).border_type(BorderType::Rounded), frame.area());
+    }
+
+    fn handle_inputs(&mut self, key: KeyEvent) -> ViewAction {
+        let action = self.settings.borrow().keymap.action_for(key);
+        let bindings = self.bindings();
+        key_table::dispatch(self, &bindings, action, key).unwrap_or(ViewAction::Nothing)
+    }
+
+    fn help(&self) -> Vec<HelpEntry> {
+        key_table::help_entries(&self.bindings(), &self.settings.borrow().keymap)
+    }
+
+    fn update(&mut self, _now: Instant) -> ViewAction {
+        if let Some(discovery) = self.discovery.as_mut() && let Err(error) = discovery.update() {
+            self.error = Some(error.to_string());
+            self.discovery = None;
+        }
+        ViewAction::Nothing
+    }
+
+    //Hosts appear and disappear while the lobby is open
+    fn is_live(&self) -> bool {
+        self.discovery.is_some()
+    }
+}
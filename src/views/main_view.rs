@@ -1,25 +1,40 @@
-use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use std::cell::RefCell;
+use std::rc::Rc;
+use ratatui::crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Direction, Layout, Margin, Rect};
 use ratatui::style::Style;
 use ratatui::widgets::{Block, BorderType, Borders, List, ListState, Paragraph};
+use crate::enums::dialog_result::DialogResult;
+use crate::enums::key_action::KeyAction;
+use crate::enums::layout_size::{LayoutSize, MIN_HEIGHT, MIN_WIDTH};
 use crate::enums::player_type::PlayerType;
 use crate::enums::view_action::ViewAction;
+use crate::helpers::key_table;
+use crate::helpers::key_table::{Binding, HelpEntry};
+use crate::helpers::mouse;
+use crate::services::locale::{tr, tr_args};
+use crate::services::saved_game;
+use crate::services::settings::Settings;
 use crate::traits::view_model::ViewModel;
 use crate::services::game_art as Art;
 
 pub struct MainView{
     main_list: ListState,
-    list_options: Vec<MenuOption>
+    list_options: Vec<MenuOption>,
+    list_area: Rect,
+    settings: Rc<RefCell<Settings>>,
 }
 
-const RECOMMENDED_WIDTH: u16 = 97;
-const RECOMMENDED_HEIGHT: u16 = 31;
-
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum MenuOption{
+    ContinueGame,
     StartAiGame,
     StartLocalGame,
+    StartEngineGame,
+    HostNetworkGame,
+    JoinNetworkGame,
+    Statistics,
     Settings,
     Quit,
 }
@@ -27,31 +42,64 @@ pub enum MenuOption{
 impl MenuOption{
     pub fn as_str(&self) -> &str{
         match self {
-            MenuOption::StartAiGame => "Start Game with Ai",
-            MenuOption::StartLocalGame => "Start Local Co-op game",
-            MenuOption::Quit => "Quit",
-            MenuOption::Settings => "Settings"
+            MenuOption::ContinueGame => tr("Continue saved game"),
+            MenuOption::StartAiGame => tr("Start Game with Ai"),
+            MenuOption::StartLocalGame => tr("Start Local Co-op game"),
+            MenuOption::StartEngineGame => tr("Start Game with external Engine"),
+            MenuOption::HostNetworkGame => tr("Host network game"),
+            MenuOption::JoinNetworkGame => tr("Join network game"),
+            MenuOption::Statistics => tr("Statistics"),
+            MenuOption::Quit => tr("Quit"),
+            MenuOption::Settings => tr("Settings")
         }
     }
 }
 
 impl MainView{
-    pub fn new() -> MainView{
+    pub fn new(settings: Rc<RefCell<Settings>>) -> MainView{
         let mut list_state = ListState::default();
         list_state.select(Some(0));
+        let list_options = Self::get_menu_options(&settings.borrow());
 
         MainView{
-            list_options: vec![MenuOption::StartAiGame,MenuOption::StartLocalGame,MenuOption::Settings,MenuOption::Quit],
-            main_list: list_state
+            list_options,
+            main_list: list_state,
+            list_area: Rect::default(),
+            settings,
+        }
+    }
+    //Continuing is offered only while a game is saved
+    fn get_menu_options(settings: &Settings) -> Vec<MenuOption>{
+        let mut options = Vec::new();
+        if saved_game::load(&settings.data_dir).is_some() {
+            options.push(MenuOption::ContinueGame);
         }
+        options.extend([MenuOption::StartAiGame,MenuOption::StartLocalGame,MenuOption::StartEngineGame,MenuOption::HostNetworkGame,MenuOption::JoinNetworkGame,MenuOption::Statistics,MenuOption::Settings,MenuOption::Quit]);
+        options
     }
+
+    /// Rebuilds the menu after a game was saved or continued, the same option stays selected.
+    fn refresh_options(&mut self){
+        let options = Self::get_menu_options(&self.settings.borrow());
+        let selected = self.get_selected_menu_option()
+            .and_then(|selected| options.iter().position(|option| option == selected))
+            .unwrap_or(0);
+        self.list_options = options;
+        self.main_list.select(Some(selected));
+    }
+
     fn select_option(&mut self) -> ViewAction{
         let selected =self.get_selected_menu_option();
         match selected {
             Some(option) => {
                 match option {
-                    MenuOption::StartAiGame => ViewAction::GoToGame(PlayerType::Ai),
-                    MenuOption::StartLocalGame => ViewAction::GoToGame(PlayerType::Human),
+                    MenuOption::ContinueGame => ViewAction::ContinueGame,
+                    MenuOption::StartAiGame => ViewAction::GoToSeats(PlayerType::Ai),
+                    MenuOption::StartLocalGame => ViewAction::GoToSeats(PlayerType::Human),
+                    MenuOption::StartEngineGame => ViewAction::GoToSeats(PlayerType::Engine),
+                    MenuOption::HostNetworkGame => ViewAction::HostGame,
+                    MenuOption::JoinNetworkGame => ViewAction::GoToLobby,
+                    MenuOption::Statistics => ViewAction::GoToStatistics,
                     MenuOption::Settings => ViewAction::GoToSettings,
                     MenuOption::Quit => ViewAction::Quit,
                 }
@@ -60,6 +108,16 @@ impl MainView{
         }
     }
 
+    fn bindings(&self) -> Vec<Binding<MainView>>{
+        vec![
+            Binding::action(KeyAction::Up, "Select", |view, _| {view.main_list.select_previous(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Down, "Select", |view, _| {view.main_list.select_next(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Confirm, "Open", |view, _| view.select_option()),
+            Binding::action(KeyAction::Back, "Quit", |_, _| ViewAction::Quit),
+            Binding::help(),
+        ]
+    }
+
     fn get_selected_menu_option(&mut self) -> Option<&MenuOption> {
         let index = self.main_list.selected();
         if let Some(index) = index {
@@ -69,23 +127,27 @@ impl MainView{
         }
     }
 
-    fn render_terminal_size_warning(frame:&mut Frame, area: Rect){
+    fn render_terminal_size_warning(frame:&mut Frame, area: Rect, style: Style){
         let current_width = frame.area().width;
         let current_height = frame.area().height;
 
-        if current_width != RECOMMENDED_WIDTH || current_height != RECOMMENDED_HEIGHT{
-            let warning_text = format!("Your current terminal size is {},{} recommended is {},{}",
-            current_width,current_height,RECOMMENDED_WIDTH,RECOMMENDED_HEIGHT,);
+        if LayoutSize::is_too_small(frame.area()){
+            let warning_text = tr_args("Your current terminal size is {},{} the game needs at least {},{}",
+            &[&current_width,&current_height,&MIN_WIDTH,&MIN_HEIGHT]);
 
-            frame.render_widget(Paragraph::new(warning_text).centered().style(Style::new().red()),area);
+            frame.render_widget(Paragraph::new(warning_text).centered().style(style),area);
         }
 
     }
 }
 impl ViewModel for MainView{
     fn render_widgets(&mut self, frame: &mut Frame){
+        let theme = self.settings.borrow().theme.clone();
 
-        let title = Art::tic_tac_toe();
+        //The title art of some languages is wider than the smallest normal layout
+        let art = Art::tic_tac_toe();
+        let compact = LayoutSize::for_area(frame.area()) == LayoutSize::Compact || art.width() + 2 > frame.area().width as usize;
+        let title = Art::title(art, "TIC TAC TOE", compact || self.settings.borrow().plain_text).patch_style(theme.banner_style());
 
         let main_layout_rects = Layout::default()
             .direction(Direction::Vertical)
@@ -100,7 +162,7 @@ impl ViewModel for MainView{
             .centered(Constraint::Length(title.width() as u16),Constraint::Percentage(50));
 
         let main_area_center = main_layout_rects[1].inner(Margin::new(1,1));
-        Self::render_terminal_size_warning(frame, main_area_center);
+        Self::render_terminal_size_warning(frame, main_area_center, theme.loss_style());
 
         let main_area_bottom = main_layout_rects[2]
             .centered(Constraint::Percentage(75),Constraint::Percentage(75));
@@ -109,28 +171,44 @@ impl ViewModel for MainView{
 
         let list_items = self.list_options.iter().map(|item|{item.as_str()}).collect::<Vec<&str>>();
         let list = List::new(list_items)
-            .highlight_style(Style::new().reversed())
+            .highlight_style(theme.highlight_style())
             .highlight_symbol(">>")
             .repeat_highlight_symbol(true);
 
         frame.render_stateful_widget(list, main_area_bottom, &mut self.main_list);
+        self.list_area = main_area_bottom;
 
-        frame.render_widget(Block::new().borders(Borders::ALL).border_type(BorderType::Rounded), frame.area());
+        frame.render_widget(Block::new().borders(Borders::ALL).border_type(BorderType::Rounded).border_style(theme.border_style()), frame.area());
 
     }
     fn handle_inputs(&mut self, key: KeyEvent) -> ViewAction {
-        match key.code {
-            KeyCode::Esc => return ViewAction::Quit,
-            KeyCode::Up => self.main_list.select_previous(),
-            KeyCode::Down => self.main_list.select_next(),
-            KeyCode::Enter => return self.select_option(),
+        let action = self.settings.borrow().keymap.action_for(key);
+        let bindings = self.bindings();
+        key_table::dispatch(self, &bindings, action, key).unwrap_or(ViewAction::Nothing)
+    }
+
+    fn help(&self) -> Vec<HelpEntry> {
+        key_table::help_entries(&self.bindings(), &self.settings.borrow().keymap)
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) -> ViewAction {
+        let Some(index) = mouse::list_item_at(self.list_area, &self.main_list, self.list_options.len(), event.column, event.row) else {
+            return ViewAction::Nothing
+        };
+        match event.kind {
+            MouseEventKind::Moved => self.main_list.select(Some(index)),
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.main_list.select(Some(index));
+                return self.select_option();
+            }
             _ => {}
         }
         ViewAction::Nothing
     }
 
-    fn additional_actions(&mut self) {
-        
+    fn handle_result(&mut self, _result: DialogResult) -> ViewAction {
+        self.refresh_options();
+        ViewAction::Nothing
     }
 }
 
@@ -1,3 +1,14 @@
 pub mod main_view;
 pub mod game_view;
-pub mod settings_view;
\ No newline at end of file
+pub mod settings_view;
+pub mod join_view;
+pub mod lobby_view;
+pub mod seats_view;
+pub mod new_profile_view;
+pub mod statistics_view;
+pub mod theme_colors_view;
+pub mod color_picker_view;
+pub mod key_bindings_view;
+pub mod pause_view;
+pub mod confirm_view;
+pub mod help_view;
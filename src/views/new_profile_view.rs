@@ -0,0 +1,107 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::Style;
+use ratatui::widgets::{Block, BorderType, Borders, Paragraph};
+use crate::enums::player::Player;
+use crate::enums::view_action::ViewAction;
+use crate::helpers::key_table;
+use crate::helpers::key_table::{Binding, HelpEntry};
+use crate::helpers::text_input::TextInput;
+use crate::services::locale::tr;
+use crate::services::profiles::{ProfileStore, MAX_NAME_LENGTH};
+use crate::services::settings::Settings;
+use crate::traits::view_model::ViewModel;
+use crate::services::game_art as Art;
+
+pub struct NewProfileView{
+    name_input: TextInput,
+    error: Option<String>,
+    seat: Player,
+    profiles: Rc<RefCell<ProfileStore>>,
+    settings: Rc<RefCell<Settings>>,
+}
+
+impl NewProfileView{
+    pub fn new(seat: Player, profiles: Rc<RefCell<ProfileStore>>, settings: Rc<RefCell<Settings>>) -> NewProfileView{
+        NewProfileView{
+            name_input: TextInput::new("", MAX_NAME_LENGTH),
+            error: None,
+            seat,
+            profiles,
+            settings,
+        }
+    }
+
+    fn create_profile(&mut self) -> ViewAction{
+        let result = self.profiles.borrow_mut().add(self.name_input.value());
+        match result {
+            Ok(index) => {
+                self.profiles.borrow_mut().set_seat(self.seat, Some(index));
+                ViewAction::Back
+            }
+            Err(message) => {
+                self.error = Some(message);
+                ViewAction::Nothing
+            }
+        }
+    }
+
+    fn bindings(&self) -> Vec<Binding<NewProfileView>>{
+        vec![
+            Binding::key(KeyCode::Enter, "Create profile", |view, _| view.create_profile()),
+            Binding::key(KeyCode::Esc, "Back", |_, _| ViewAction::Back),
+            Binding::typing_help(),
+        ]
+    }
+}
+
+impl ViewModel for NewProfileView{
+    fn render_widgets(&mut self, frame: &mut Frame){
+        let title = Art::title(Art::players(), "Players", self.settings.borrow().plain_text);
+
+        let main_layout_rects = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(50),
+                Constraint::Length(3),
+                Constraint::Length(2),
+                Constraint::Fill(1),
+            ])
+            .split(frame.area());
+
+        let title_area = main_layout_rects[0]
+            .centered(Constraint::Length(title.width() as u16),Constraint::Percentage(50));
+        frame.render_widget(title, title_area);
+
+        let input_area = main_layout_rects[1].centered_horizontally(Constraint::Length(MAX_NAME_LENGTH as u16 + 3));
+        self.name_input.render(frame, input_area, tr("Profile name"));
+
+        if let Some(error) = &self.error {
+            frame.render_widget(Paragraph::new(error.as_str()).centered().style(Style::new().red()), main_layout_rects[2]);
+        }
+
+        let help = Paragraph::new(tr("Enter - create profile, ESC - go back")).centered();
+        frame.render_widget(help, main_layout_rects[3].centered_vertically(Constraint::Length(1)));
+
+        frame.render_widget(Block::new().borders(Borders::ALL).border_type(BorderType::Rounded), frame.area());
+    }
+
+    //Keys without a binding are typed into the name
+    fn handle_inputs(&mut self, key: KeyEvent) -> ViewAction {
+        let bindings = self.bindings();
+        if let Some(view_action) = key_table::dispatch(self, &bindings, None, key) {
+            return view_action;
+        }
+        if self.name_input.handle_key(key) {
+            self.error = None;
+        }
+        ViewAction::Nothing
+    }
+
+    fn help(&self) -> Vec<HelpEntry> {
+        key_table::help_entries(&self.bindings(), &self.settings.borrow().keymap)
+    }
+}
@@ -0,0 +1,113 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use ratatui::crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Constraint, Direction, Layout, Margin, Rect};
+use ratatui::widgets::{Block, BorderType, Clear, List, ListState, Paragraph};
+use crate::enums::dialog_result::DialogResult;
+use crate::enums::key_action::KeyAction;
+use crate::enums::pause_option::PauseOption;
+use crate::enums::player_type::PlayerType;
+use crate::enums::view_action::ViewAction;
+use crate::helpers::key_table;
+use crate::helpers::key_table::{Binding, HelpEntry};
+use crate::helpers::mouse;
+use crate::services::locale::{tr, tr_args};
+use crate::services::settings::Settings;
+use crate::traits::view_model::ViewModel;
+
+/// Overlay over a running game, the game below decides what the chosen option does.
+pub struct PauseView{
+    options: Vec<PauseOption>,
+    list: ListState,
+    list_area: Rect,
+    settings: Rc<RefCell<Settings>>,
+}
+
+impl PauseView{
+    pub fn new(opponent_type: PlayerType, settings: Rc<RefCell<Settings>>) -> PauseView{
+        let mut list = ListState::default();
+        list.select(Some(0));
+
+        PauseView{
+            options: PauseOption::get_for(opponent_type),
+            list,
+            list_area: Rect::default(),
+            settings,
+        }
+    }
+
+    fn select_option(&self) -> ViewAction{
+        match self.list.selected().and_then(|index| self.options.get(index)) {
+            Some(PauseOption::Resume) => ViewAction::Back,
+            Some(option) => ViewAction::Return(DialogResult::PauseMenu(*option)),
+            None => ViewAction::Nothing,
+        }
+    }
+
+    fn bindings(&self) -> Vec<Binding<PauseView>>{
+        vec![
+            Binding::action(KeyAction::Up, "Select", |view, _| {view.list.select_previous(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Down, "Select", |view, _| {view.list.select_next(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Confirm, "Choose", |view, _| view.select_option()),
+            Binding::action(KeyAction::Back, "Resume", |_, _| ViewAction::Back),
+            Binding::help(),
+        ]
+    }
+}
+
+impl ViewModel for PauseView{
+    fn render_widgets(&mut self, frame: &mut Frame){
+        let theme = self.settings.borrow().theme.clone();
+        let popup_area = frame.area().centered(Constraint::Length(32),Constraint::Length(self.options.len() as u16 + 5));
+        let popup_area_rects = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Fill(1),Constraint::Length(1)])
+            .split(popup_area.inner(Margin::new(1,1)));
+
+        let block = Block::bordered().border_type(BorderType::Rounded).style(theme.base_style()).border_style(theme.border_style()).title(tr("Paused")).title_alignment(Alignment::Center);
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(block, popup_area);
+
+        let list_items = self.options.iter().map(|option| option.as_str().to_string()).collect::<Vec<String>>();
+        let list = List::new(list_items)
+            .highlight_style(theme.highlight_style())
+            .highlight_symbol(">>")
+            .repeat_highlight_symbol(true);
+        frame.render_stateful_widget(list, popup_area_rects[0], &mut self.list);
+        self.list_area = popup_area_rects[0];
+
+        let keymap = &self.settings.borrow().keymap;
+        let help_text = tr_args("{} - select, {} - resume", &[&keymap.label_for(KeyAction::Confirm), &keymap.label_for(KeyAction::Back)]);
+        frame.render_widget(Paragraph::new(help_text).centered(), popup_area_rects[1]);
+    }
+
+    fn handle_inputs(&mut self, key: KeyEvent) -> ViewAction{
+        let action = self.settings.borrow().keymap.action_for(key);
+        let bindings = self.bindings();
+        key_table::dispatch(self, &bindings, action, key).unwrap_or(ViewAction::Nothing)
+    }
+
+    fn help(&self) -> Vec<HelpEntry>{
+        key_table::help_entries(&self.bindings(), &self.settings.borrow().keymap)
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) -> ViewAction{
+        let Some(index) = mouse::list_item_at(self.list_area, &self.list, self.options.len(), event.column, event.row) else {
+            return ViewAction::Nothing
+        };
+        match event.kind {
+            MouseEventKind::Moved => self.list.select(Some(index)),
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.list.select(Some(index));
+                return self.select_option();
+            }
+            _ => {}
+        }
+        ViewAction::Nothing
+    }
+
+    fn is_overlay(&self) -> bool{
+        true
+    }
+}
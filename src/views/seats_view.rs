@@ -0,0 +1,209 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use ratatui::crossterm::event::KeyEvent;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::Style;
+use ratatui::widgets::{Block, BorderType, Borders, List, ListState, Paragraph};
+use crate::enums::first_move::FirstMove;
+use crate::enums::key_action::KeyAction;
+use crate::enums::player::Player;
+use crate::enums::player_type::PlayerType;
+use crate::enums::view_action::ViewAction;
+use crate::helpers::key_table;
+use crate::helpers::key_table::{Binding, HelpEntry};
+use crate::helpers::notifications::Notification;
+use crate::services::locale::{tr, tr_args};
+use crate::services::profiles::ProfileStore;
+use crate::services::settings::Settings;
+use crate::traits::view_model::ViewModel;
+use crate::services::game_art as Art;
+
+pub struct SeatsView{
+    main_list: ListState,
+    list_options: Vec<MenuOption>,
+    opponent_type: PlayerType,
+    settings: Rc<RefCell<Settings>>,
+    profiles: Rc<RefCell<ProfileStore>>,
+    notifications: Vec<Notification>,
+}
+
+#[derive(Debug)]
+pub enum MenuOption{
+    Seat(Player),
+    Personality,
+    /// Engine games only, leads to the settings where the command is entered.
+    Engine,
+    TimeControl,
+    NewProfile,
+    StartGame,
+    Quit,
+}
+
+impl SeatsView{
+    pub fn new(opponent_type: PlayerType, settings: Rc<RefCell<Settings>>, profiles: Rc<RefCell<ProfileStore>>) -> SeatsView{
+        let mut list_state = ListState::default();
+        list_state.select(Some(0));
+
+        let mut list_options = vec![MenuOption::Seat(Player::Player)];
+        match opponent_type {
+            PlayerType::Human => list_options.push(MenuOption::Seat(Player::Opponent)),
+            PlayerType::Ai => list_options.push(MenuOption::Personality),
+            PlayerType::Engine => list_options.push(MenuOption::Engine),
+            _ => {}
+        }
+        list_options.extend([MenuOption::TimeControl, MenuOption::NewProfile, MenuOption::StartGame, MenuOption::Quit]);
+
+        SeatsView{
+            main_list: list_state,
+            list_options,
+            opponent_type,
+            settings,
+            profiles,
+            notifications: Vec::new(),
+        }
+    }
+
+    fn option_text(&self, option: &MenuOption) -> String{
+        match option {
+            MenuOption::Seat(player) => {
+                //Against the AI and engines the player's mark follows the first move setting
+                let first_move = self.settings.borrow().first_move;
+                let seat = tr(match (*player, self.opponent_type, first_move) {
+                    (Player::Opponent, _, _) => "Circle (O)",
+                    (Player::Player, PlayerType::Ai | PlayerType::Engine, FirstMove::Opponent) => "Circle (O)",
+                    (Player::Player, PlayerType::Ai | PlayerType::Engine, FirstMove::Random) => "You",
+                    (Player::Player, _, _) => "Cross (X)",
+                });
+                format!("{}: < {} >", seat, self.profiles.borrow().seat_label(*player))
+            }
+            MenuOption::Personality => {
+                let personality = &self.settings.borrow().personality;
+                let rating = format!("{:.0}", self.profiles.borrow().ai_rating(personality.level));
+                tr_args("AI: < {} - {} ({}) >", &[&personality.name, &personality.level.label(), &rating])
+            }
+            MenuOption::Engine => match &self.settings.borrow().engine {
+                Some(engine) => tr_args("Engine: {} (change in Settings)", &[&engine.command_line()]),
+                None => tr("Engine: not set, choose one in Settings").to_string(),
+            },
+            MenuOption::TimeControl => tr_args("Time control: < {} >", &[&self.settings.borrow().time_control.label()]),
+            MenuOption::NewProfile => tr("Create new profile").to_string(),
+            MenuOption::StartGame => tr("Start game").to_string(),
+            MenuOption::Quit => tr("Go back").to_string(),
+        }
+    }
+
+    fn get_selected_menu_option(&self) -> Option<&MenuOption> {
+        self.main_list.selected().and_then(|index| self.list_options.get(index))
+    }
+
+    /// New profiles go to the first seat without one.
+    fn seat_for_new_profile(&self) -> Player{
+        let profiles = self.profiles.borrow();
+        if self.opponent_type == PlayerType::Human && profiles.seat(Player::Player).is_some() && profiles.seat(Player::Opponent).is_none() {
+            Player::Opponent
+        }else{
+            Player::Player
+        }
+    }
+
+    fn select_option(&mut self) -> ViewAction{
+        match self.get_selected_menu_option() {
+            Some(MenuOption::Seat(_)) | Some(MenuOption::Personality) | Some(MenuOption::TimeControl) => {
+                self.cycle_selected_option(true);
+                ViewAction::Nothing
+            }
+            Some(MenuOption::Engine) => ViewAction::GoToSettings,
+            Some(MenuOption::NewProfile) => ViewAction::GoToNewProfile(self.seat_for_new_profile()),
+            Some(MenuOption::StartGame) => ViewAction::GoToGame(self.opponent_type),
+            Some(MenuOption::Quit) => ViewAction::Back,
+            None => ViewAction::Nothing,
+        }
+    }
+
+    fn cycle_selected_option(&mut self, forward: bool){
+        match self.get_selected_menu_option() {
+            Some(MenuOption::Seat(player)) => {
+                let player = *player;
+                self.profiles.borrow_mut().cycle_seat(player, forward);
+            }
+            Some(MenuOption::Personality) => {self.cycle_personality(forward)}
+            Some(MenuOption::TimeControl) => {
+                let mut settings = self.settings.borrow_mut();
+                settings.time_control = if forward {settings.time_control.next()} else {settings.time_control.previous()};
+            }
+            _ => {}
+        }
+    }
+
+    fn cycle_personality(&mut self, forward: bool){
+        let mut settings = self.settings.borrow_mut();
+        for problem in settings.cycle_personality(forward) {
+            self.notifications.push(Notification::error(tr_args("Skipped personality file {}", &[&problem])));
+        }
+        if let Err(error) = settings.save_preferences() {
+            self.notifications.push(Notification::error(tr_args("Could not save settings: {}", &[&error])));
+        }
+    }
+
+    fn bindings(&self) -> Vec<Binding<SeatsView>>{
+        vec![
+            Binding::action(KeyAction::Up, "Select", |view, _| {view.main_list.select_previous(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Down, "Select", |view, _| {view.main_list.select_next(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Left, "Change value", |view, _| {view.cycle_selected_option(false); ViewAction::Nothing}),
+            Binding::action(KeyAction::Right, "Change value", |view, _| {view.cycle_selected_option(true); ViewAction::Nothing}),
+            Binding::action(KeyAction::Confirm, "Choose", |view, _| view.select_option()),
+            Binding::action(KeyAction::Back, "Back to menu", |_, _| ViewAction::Back),
+            Binding::help(),
+        ]
+    }
+}
+
+impl ViewModel for SeatsView{
+    fn render_widgets(&mut self, frame: &mut Frame){
+        let title = Art::title(Art::players(), "Players", self.settings.borrow().plain_text);
+
+        let main_layout_rects = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(50),
+                Constraint::Fill(1),
+                Constraint::Length(2),
+            ])
+            .split(frame.area());
+
+        let title_area = main_layout_rects[0]
+            .centered(Constraint::Length(title.width() as u16),Constraint::Percentage(50));
+        frame.render_widget(title, title_area);
+
+        let list_items = self.list_options.iter().map(|option| self.option_text(option)).collect::<Vec<String>>();
+        let list = List::new(list_items)
+            .highlight_style(Style::new().reversed())
+            .highlight_symbol(">>")
+            .repeat_highlight_symbol(true);
+        let list_area = main_layout_rects[1].centered_horizontally(Constraint::Percentage(75));
+        frame.render_stateful_widget(list, list_area, &mut self.main_list);
+
+        let keymap = &self.settings.borrow().keymap;
+        let help_text = tr_args("{},{} - change selection, {} - confirm, {} - go back",
+            &[&keymap.label_for(KeyAction::Left), &keymap.label_for(KeyAction::Right), &keymap.label_for(KeyAction::Confirm), &keymap.label_for(KeyAction::Back)]);
+        let help = Paragraph::new(help_text).centered();
+        frame.render_widget(help, main_layout_rects[2]);
+
+        frame.render_widget(Block::new().borders(Borders::ALL).border_type(BorderType::Rounded), frame.area());
+    }
+
+    fn handle_inputs(&mut self, key: KeyEvent) -> ViewAction {
+        let action = self.settings.borrow().keymap.action_for(key);
+        let bindings = self.bindings();
+        key_table::dispatch(self, &bindings, action, key).unwrap_or(ViewAction::Nothing)
+    }
+
+    fn help(&self) -> Vec<HelpEntry> {
+        key_table::help_entries(&self.bindings(), &self.settings.borrow().keymap)
+    }
+
+    fn take_notifications(&mut self) -> Vec<Notification>{
+        std::mem::take(&mut self.notifications)
+    }
+}
@@ -1,224 +1,466 @@
-use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::Frame;
-use ratatui::layout::{Alignment, Constraint, Direction, Layout, Margin};
-use ratatui::style::{Color, Style, Styled};
-use ratatui::widgets::{Block, BorderType, Borders, Clear, List, ListState};
-use crate::enums::player::Player;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::Stylize;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState};
+use crate::enums::confirmation::Confirmation;
+use crate::enums::dialog_result::DialogResult;
+use crate::enums::key_action::KeyAction;
 use crate::enums::view_action::ViewAction;
+use crate::helpers::key_table;
+use crate::helpers::key_table::{Binding, HelpEntry};
+use crate::helpers::mouse;
+use crate::helpers::notifications::Notification;
+use crate::helpers::setting_widget::SettingWidget;
+use crate::helpers::text_input::TextInput;
+use crate::services::engine::EngineConfig;
+use crate::services::art_pack::ArtPack;
+use crate::services::keymap::KeyMap;
+use crate::services::locale;
+use crate::services::locale::{tr, tr_args};
+use crate::services::settings::{Settings, MAX_AI_THINK_TIME};
+use crate::services::theme::Theme;
 use crate::traits::view_model::ViewModel;
 use crate::services::game_art as Art;
 
+const AI_THINK_TIME_STEP: Duration = Duration::from_millis(250);
+const MAX_ENGINE_COMMAND_LENGTH: usize = 200;
+
 pub struct SettingsView{
     main_list: ListState,
-    color_list: ListState,
-    list_options: Vec<MenuOption>,
-    color_options: Vec<ColorOption>,
-    new_color_player: Player,
-    show_modal: bool,
+    rows: Vec<Row>,
+    list_area: Rect,
+    /// Open while the engine command is typed, Enter saves it and Esc throws it away.
+    engine_input: Option<TextInput>,
+    settings: Rc<RefCell<Settings>>,
+    notifications: Vec<Notification>,
 }
 
-
+/// Line of the settings list, only options can be selected.
 #[derive(Debug)]
-pub enum MenuOption{
-    ChangeOpponentColor,
-    ChangePlayerColor,
-    Quit
+enum Row{
+    Header(Section),
+    Gap,
+    Option(MenuOption),
 }
 
-impl MenuOption{
+#[derive(Debug, Clone, Copy)]
+pub enum Section{
+    Gameplay,
+    Display,
+    Controls,
+}
+
+impl Section{
     pub fn as_str(&self) -> &str{
         match self {
-            MenuOption::ChangeOpponentColor => "Change opponent color",
-            MenuOption::ChangePlayerColor => "Change player color",
-            MenuOption::Quit => "Go back"
+            Section::Gameplay => tr("Gameplay"),
+            Section::Display => tr("Display"),
+            Section::Controls => tr("Controls"),
         }
     }
 }
 
-#[derive(Debug,Clone,Copy)]
-pub enum ColorOption{
-    Green,
-    Blue,
-    Magenta,
-    Yellow,
-    Red,
-    Miku,
-    MorningSun,
-    Pink,
-    Orange
+#[derive(Debug)]
+pub enum MenuOption{
+    Personality,
+    FirstMove,
+    Variant,
+    AiThinkTime,
+    Engine,
+    Language,
+    Theme,
+    EditColors,
+    ArtPack,
+    Animations,
+    TilePatterns,
+    PlainText,
+    KeyPreset,
+    RebindKeys,
+    ResetDefaults,
+    Quit
 }
 
-impl ColorOption{
-    pub fn as_color(&self) -> Color{
+impl MenuOption{
+    pub fn as_str(&self) -> &str{
         match self {
-            ColorOption::Green => {Color::Green}
-            ColorOption::Blue => {Color::Blue}
-            ColorOption::Magenta => {Color::Magenta}
-            ColorOption::Yellow => {Color::Yellow},
-            ColorOption::Red => {Color::Red},
-            ColorOption::Miku => {Color::Rgb(134,206,203)},
-            ColorOption::MorningSun => {Color::Rgb(255,255,26)}
-            ColorOption::Pink => {Color::Rgb(255,51,255)}
-            ColorOption::Orange => {Color::Rgb(255,102,0)}
+            MenuOption::Personality => tr("AI opponent"),
+            MenuOption::FirstMove => tr("First move"),
+            MenuOption::Variant => tr("Rules"),
+            MenuOption::AiThinkTime => tr("AI think delay"),
+            MenuOption::Engine => tr("Engine command"),
+            MenuOption::Language => tr("Language"),
+            MenuOption::Theme => tr("Theme"),
+            MenuOption::EditColors => tr("Edit colors"),
+            MenuOption::ArtPack => tr("Art pack"),
+            MenuOption::Animations => tr("Animations"),
+            MenuOption::TilePatterns => tr("Tile patterns"),
+            MenuOption::PlainText => tr("Plain text mode"),
+            MenuOption::KeyPreset => tr("Key preset"),
+            MenuOption::RebindKeys => tr("Rebind keys"),
+            MenuOption::ResetDefaults => tr("Reset to defaults"),
+            MenuOption::Quit => tr("Go back")
         }
     }
-
-    pub fn get_all() -> [ColorOption; 9]{
-        [
-            ColorOption::Green,
-            ColorOption::Blue,
-            ColorOption::Magenta,
-            ColorOption::Yellow,
-            ColorOption::Red,
-            ColorOption::Miku,
-            ColorOption::MorningSun,
-            ColorOption::Pink,
-            ColorOption::Orange
-        ]
-    }
 }
 
 impl SettingsView{
-    pub fn new() -> SettingsView{
+    pub fn new(settings: Rc<RefCell<Settings>>) -> SettingsView{
         let mut list_state = ListState::default();
-        list_state.select(Some(0));
-
-        let mut list_state2 = ListState::default();
-        list_state2.select(Some(0));
+        //The first row is the gameplay header
+        list_state.select(Some(1));
 
         SettingsView{
-            list_options: vec![MenuOption::ChangePlayerColor,MenuOption::ChangeOpponentColor,MenuOption::Quit],
-            color_options: ColorOption::get_all().to_vec(),
+            rows: vec![
+                Row::Header(Section::Gameplay),
+                Row::Option(MenuOption::Personality),
+                Row::Option(MenuOption::FirstMove),
+                Row::Option(MenuOption::Variant),
+                Row::Option(MenuOption::AiThinkTime),
+                Row::Option(MenuOption::Engine),
+                Row::Gap,
+                Row::Header(Section::Display),
+                Row::Option(MenuOption::Language),
+                Row::Option(MenuOption::Theme),
+                Row::Option(MenuOption::EditColors),
+                Row::Option(MenuOption::ArtPack),
+                Row::Option(MenuOption::Animations),
+                Row::Option(MenuOption::TilePatterns),
+                Row::Option(MenuOption::PlainText),
+                Row::Gap,
+                Row::Header(Section::Controls),
+                Row::Option(MenuOption::KeyPreset),
+                Row::Option(MenuOption::RebindKeys),
+                Row::Gap,
+                Row::Option(MenuOption::ResetDefaults),
+                Row::Option(MenuOption::Quit),
+            ],
             main_list: list_state,
-            show_modal: false,
-            color_list: list_state2,
-            new_color_player: Player::Player,
+            list_area: Rect::default(),
+            engine_input: None,
+            settings,
+            notifications: Vec::new(),
         }
     }
-    fn select_menu_option(&mut self) -> Option<ViewAction>{
-        let selected =self.get_selected_menu_option();
-        match selected {
-            Some(option) => {
-                match option {
-                    MenuOption::ChangeOpponentColor => {
-                        self.change_new_player_color(Player::Opponent);
-                        self.toggle_modal()
-                    }
-                    MenuOption::ChangePlayerColor => {
-                        self.change_new_player_color(Player::Player);
-                        self.toggle_modal()
-                    }
-                    MenuOption::Quit => {return Some(ViewAction::GoToMain)}
+
+    fn widget(&self, option: &MenuOption) -> Option<SettingWidget>{
+        let settings = self.settings.borrow();
+        let widget = match option {
+            MenuOption::Personality => SettingWidget::Choice(format!("{} ({})", settings.personality.name, settings.personality.level.label())),
+            MenuOption::FirstMove => SettingWidget::Choice(settings.first_move.label().to_string()),
+            MenuOption::Variant => SettingWidget::Choice(settings.variant.label().to_string()),
+            MenuOption::AiThinkTime => SettingWidget::Slider{
+                value: settings.ai_think_time.as_millis() as u32,
+                min: 0,
+                max: MAX_AI_THINK_TIME.as_millis() as u32,
+                text: format!("{:.2} s", settings.ai_think_time.as_secs_f32()),
+            },
+            MenuOption::Engine => SettingWidget::Text(match &settings.engine {
+                Some(engine) => engine.command_line(),
+                None => tr("not set").to_string(),
+            }),
+            MenuOption::Language => SettingWidget::Choice(settings.language.as_str().to_string()),
+            MenuOption::Theme => SettingWidget::Choice(settings.theme.name.clone()),
+            MenuOption::ArtPack => SettingWidget::Choice(settings.art_pack.clone()),
+            MenuOption::Animations => SettingWidget::Toggle(settings.animations),
+            MenuOption::TilePatterns => SettingWidget::Toggle(settings.tile_patterns),
+            MenuOption::PlainText => SettingWidget::Toggle(settings.plain_text),
+            MenuOption::KeyPreset => SettingWidget::Choice(settings.keymap.preset.as_str().to_string()),
+            MenuOption::EditColors | MenuOption::RebindKeys | MenuOption::ResetDefaults => SettingWidget::Action,
+            MenuOption::Quit => return None,
+        };
+        Some(widget)
+    }
+
+    /// Labels are padded to the longest one so the widgets line up.
+    fn list_items(&self) -> Vec<ListItem<'static>>{
+        let settings = self.settings.borrow();
+        let accent = settings.theme.accent_style();
+        let label_width = self.rows.iter()
+            .filter_map(|row| if let Row::Option(option) = row {Some(option.as_str().chars().count())} else {None})
+            .max()
+            .unwrap_or(0);
+
+        self.rows.iter().map(|row| match row {
+            Row::Header(section) => ListItem::new(Line::from(section.as_str().to_string()).style(settings.theme.banner_style()).bold()),
+            Row::Gap => ListItem::new(""),
+            Row::Option(option) => {
+                let mut spans = vec![Span::raw(format!("{:<width$}  ", option.as_str(), width = label_width))];
+                if let Some(widget) = self.widget(option) {
+                    spans.extend(widget.spans(accent, settings.plain_text));
                 }
+                ListItem::new(Line::from(spans))
             }
-            _ => {}
+        }).collect()
+    }
+
+    fn save_preferences(&mut self){
+        if let Err(error) = self.settings.borrow().save_preferences() {
+            self.notifications.push(Notification::error(tr_args("Could not save settings: {}", &[&error])));
         }
-        None
     }
 
-    fn get_selected_menu_option(&mut self) -> Option<&MenuOption> {
-        let index = self.main_list.selected();
-        if let Some(index) = index {
-            self.list_options.get(index)
-        }else{
-            None
+    fn cycle_personality(&mut self, forward: bool){
+        let problems = self.settings.borrow_mut().cycle_personality(forward);
+        for problem in problems {
+            self.notifications.push(Notification::error(tr_args("Skipped personality file {}", &[&problem])));
         }
+        self.save_preferences();
     }
 
-    fn get_selected_color_option(&mut self) -> Option<&ColorOption> {
-        let index = self.color_list.selected();
-        if let Some(index) = index {
-            self.color_options.get(index)
+    fn cycle_first_move(&mut self, forward: bool){
+        {
+            let mut settings = self.settings.borrow_mut();
+            settings.first_move = if forward {settings.first_move.next()} else {settings.first_move.previous()};
         }
-        else{
-            None
+        self.save_preferences();
+    }
+
+    fn cycle_variant(&mut self, forward: bool){
+        {
+            let mut settings = self.settings.borrow_mut();
+            settings.variant = if forward {settings.variant.next()} else {settings.variant.previous()};
         }
+        self.save_preferences();
     }
 
-    fn select_color_option(&mut self) -> Option<ViewAction> {
-        if let Some(color_option) = self.get_selected_color_option(){
-            return Some(ViewAction::ChangeColor((color_option.as_color(),self.new_color_player)));
+    fn change_ai_think_time(&mut self, longer: bool){
+        {
+            let mut settings = self.settings.borrow_mut();
+            settings.ai_think_time = if longer {
+                (settings.ai_think_time + AI_THINK_TIME_STEP).min(MAX_AI_THINK_TIME)
+            }else{
+                settings.ai_think_time.saturating_sub(AI_THINK_TIME_STEP)
+            };
         }
-        None
+        self.save_preferences();
     }
 
-    fn change_new_player_color(&mut self,player: Player){
-        self.new_color_player = player;
+    fn edit_engine(&mut self){
+        let command_line = self.settings.borrow().engine.as_ref().map(EngineConfig::command_line).unwrap_or_default();
+        self.engine_input = Some(TextInput::new(&command_line, MAX_ENGINE_COMMAND_LENGTH));
     }
 
-    fn toggle_modal(&mut self){
-        self.show_modal = !self.show_modal;
+    /// An empty command removes the engine, the next engine game starts the new one.
+    fn save_engine(&mut self) -> ViewAction{
+        let Some(input) = self.engine_input.take() else { return ViewAction::Nothing };
+        let timeouts = self.settings.borrow().engine.clone();
+        let engine = EngineConfig::new(input.value()).map(|mut engine| {
+            //Timeouts given with --engine-timeout stay
+            if let Some(previous) = timeouts {
+                engine.handshake_timeout = previous.handshake_timeout;
+                engine.move_timeout = previous.move_timeout;
+            }
+            engine
+        });
+        self.settings.borrow_mut().engine = engine;
+        self.save_preferences();
+        ViewAction::Nothing
     }
 
-    fn draw_modal(&mut self, frame: &mut Frame) {
-        if self.show_modal {
-            let popup_area = frame.area().centered(Constraint::Percentage(50),Constraint::Percentage(50));
-            let popup_area_rects = Layout::default()
-                .direction(Direction::Vertical)
-                .constraints([Constraint::Percentage(80),Constraint::Percentage(20)])
-                .split(popup_area.inner(Margin::new(1,1)));
+    fn engine_input_bindings() -> Vec<Binding<SettingsView>>{
+        vec![
+            Binding::key(KeyCode::Enter, "Save engine command", |view, _| view.save_engine()),
+            Binding::key(KeyCode::Esc, "Cancel", |view, _| {view.engine_input = None; ViewAction::Nothing}),
+            Binding::typing_help(),
+        ]
+    }
 
-            let popup_top = popup_area_rects[0];
-            let popup_bottom = popup_area_rects[1];
+    /// Art and language in use are switched here, the settings only hold their names.
+    fn reset_to_defaults(&mut self){
+        let result = self.settings.borrow_mut().reset_to_defaults();
+        Art::use_pack(ArtPack::built_in());
+        locale::use_language(self.settings.borrow().language);
+        match result {
+            Ok(()) => self.notifications.push(Notification::success(tr("Settings reset to defaults"))),
+            Err(error) => self.notifications.push(Notification::error(tr_args("Could not save settings: {}", &[&error]))),
+        }
+    }
 
-            //rendering border
-            let block = Block::bordered().title("Select color").title_alignment(Alignment::Center);
-            frame.render_widget(Clear, popup_area); //Clear area
-            frame.render_widget(block, popup_area);
+    fn change_theme(&mut self, change: impl FnOnce(&mut Theme)){
+        if let Err(error) = self.settings.borrow_mut().change_theme(change) {
+            self.notifications.push(Notification::error(tr_args("Could not save theme: {}", &[&error])));
+        }
+    }
 
-            //rendering color list
-            let list_items = self.color_options.iter().map(|color| format!("{:?}",color)).collect::<Vec<String>>();
-            let list = List::new(list_items)
-                .highlight_style(Style::new().reversed())
-                .highlight_symbol(">>")
-                .repeat_highlight_symbol(true);
+    fn cycle_theme(&mut self, forward: bool){
+        let theme = {
+            let current = &self.settings.borrow().theme;
+            if forward {current.next_built_in()} else {current.previous_built_in()}
+        };
+        self.change_theme(|current| *current = theme);
+    }
 
-            frame.render_stateful_widget(list, popup_top, &mut self.color_list);
+    /// Switches to the next pack found in the data directory, skipped files are reported as errors.
+    fn cycle_art_pack(&mut self, forward: bool){
+        let mut settings = self.settings.borrow_mut();
+        let packs = ArtPack::available(&settings.data_dir);
+        let index = packs.iter().position(|name| *name == settings.art_pack).unwrap_or(0);
+        let next = if forward {(index + 1) % packs.len()} else {(index + packs.len() - 1) % packs.len()};
+
+        let pack = match ArtPack::load(&settings.data_dir, &packs[next]) {
+            Ok(pack) => pack,
+            Err(error) => {
+                self.notifications.push(Notification::error(tr_args("Could not read art pack {}: {}", &[&packs[next], &error])));
+                return;
+            }
+        };
+        if let Err(error) = pack.save_selection(&settings.data_dir) {
+            self.notifications.push(Notification::error(tr_args("Could not save art pack: {}", &[&error])));
+        }
+        for problem in &pack.problems {
+            self.notifications.push(Notification::error(tr_args("Skipped art file {}", &[&problem])));
+        }
+        settings.art_pack = pack.name.clone();
+        Art::use_pack(pack);
+    }
 
-            //rendering color preview
-            if let Some(color) = self.get_selected_color_option(){
-                let color_block = Block::new().style(Style::new().bg(color.as_color()));
-                frame.render_widget(color_block, popup_bottom);
+    fn toggle(&mut self, option: impl FnOnce(&mut Settings) -> &mut bool){
+        {
+            let mut settings = self.settings.borrow_mut();
+            let value = option(&mut settings);
+            *value = !*value;
+        }
+        self.save_preferences();
+    }
+
+    /// Switches every text right away, the art of the new language included.
+    fn cycle_language(&mut self, forward: bool){
+        let mut settings = self.settings.borrow_mut();
+        settings.language = if forward {settings.language.next()} else {settings.language.previous()};
+        locale::use_language(settings.language);
+        if let Err(error) = settings.save_preferences() {
+            self.notifications.push(Notification::error(tr_args("Could not save settings: {}", &[&error])));
+        }
+    }
+
+    fn cycle_key_preset(&mut self, forward: bool){
+        let preset = self.settings.borrow().keymap.preset;
+        let preset = if forward {preset.next()} else {preset.previous()};
+        if let Err(error) = self.settings.borrow_mut().change_keymap(|keymap| *keymap = KeyMap::from_preset(preset)) {
+            self.notifications.push(Notification::error(tr_args("Could not save key bindings: {}", &[&error])));
+        }
+    }
+
+    fn select_menu_option(&mut self) -> Option<ViewAction>{
+        let selected =self.get_selected_menu_option();
+        if let Some(option) = selected {
+            match option {
+                MenuOption::Personality => {self.cycle_personality(true)}
+                MenuOption::FirstMove => {self.cycle_first_move(true)}
+                MenuOption::Variant => {self.cycle_variant(true)}
+                MenuOption::AiThinkTime => {self.change_ai_think_time(true)}
+                MenuOption::Engine => {self.edit_engine()}
+                MenuOption::Language => {self.cycle_language(true)}
+                MenuOption::Theme => {self.cycle_theme(true)}
+                MenuOption::EditColors => {return Some(ViewAction::OpenThemeColors)}
+                MenuOption::ArtPack => {self.cycle_art_pack(true)}
+                MenuOption::Animations => {self.toggle(|settings| &mut settings.animations)}
+                MenuOption::TilePatterns => {self.toggle(|settings| &mut settings.tile_patterns)}
+                MenuOption::PlainText => {self.toggle(|settings| &mut settings.plain_text)}
+                MenuOption::KeyPreset => {self.cycle_key_preset(true)}
+                MenuOption::RebindKeys => {return Some(ViewAction::OpenKeyBindings)}
+                MenuOption::ResetDefaults => {return Some(ViewAction::OpenConfirm(Confirmation::ResetSettings))}
+                MenuOption::Quit => {return Some(ViewAction::Back)}
             }
         }
+        None
+    }
+
+    fn get_selected_menu_option(&self) -> Option<&MenuOption> {
+        let index = self.main_list.selected();
+        if let Some(index) = index {
+            self.option_at(index)
+        }else{
+            None
+        }
     }
 
-    fn handle_input_menu(&mut self, key: KeyEvent) -> ViewAction{
-        match key.code {
-            KeyCode::Esc => return ViewAction::GoToMain,
-            KeyCode::Up => self.main_list.select_previous(),
-            KeyCode::Down => self.main_list.select_next(),
-            KeyCode::Enter =>  match self.select_menu_option() {
-                None => {}
-                Some(action) => {return action;}
-            },
+    fn option_at(&self, index: usize) -> Option<&MenuOption>{
+        match self.rows.get(index) {
+            Some(Row::Option(option)) => Some(option),
+            _ => None,
+        }
+    }
+
+    /// Selects the nearest option above or below, headers and gaps are skipped.
+    fn move_selection(&mut self, down: bool){
+        let current = self.main_list.selected().unwrap_or(0);
+        let next = if down {
+            (current + 1..self.rows.len()).find(|index| self.option_at(*index).is_some())
+        }else{
+            (0..current).rev().find(|index| self.option_at(*index).is_some())
+        };
+        match next {
+            Some(index) => self.main_list.select(Some(index)),
+            //Scrolls back to the header above the first option
+            None if !down => *self.main_list.offset_mut() = 0,
+            None => {}
+        }
+    }
+
+    fn change_selected_option(&mut self, forward: bool){
+        match self.get_selected_menu_option() {
+            Some(MenuOption::Personality) => self.cycle_personality(forward),
+            Some(MenuOption::FirstMove) => self.cycle_first_move(forward),
+            Some(MenuOption::Variant) => self.cycle_variant(forward),
+            Some(MenuOption::AiThinkTime) => self.change_ai_think_time(forward),
+            Some(MenuOption::Language) => self.cycle_language(forward),
+            Some(MenuOption::Theme) => self.cycle_theme(forward),
+            Some(MenuOption::ArtPack) => self.cycle_art_pack(forward),
+            Some(MenuOption::Animations) => self.toggle(|settings| &mut settings.animations),
+            Some(MenuOption::TilePatterns) => self.toggle(|settings| &mut settings.tile_patterns),
+            Some(MenuOption::PlainText) => self.toggle(|settings| &mut settings.plain_text),
+            Some(MenuOption::KeyPreset) => self.cycle_key_preset(forward),
             _ => {}
         }
-        ViewAction::Nothing
     }
 
-    fn handle_input_modal(&mut self, key: KeyEvent)-> ViewAction{
-        match key.code {
-            KeyCode::Esc => self.toggle_modal(),
-            KeyCode::Up => self.color_list.select_previous(),
-            KeyCode::Down => self.color_list.select_next(),
-            KeyCode::Enter => {
-                self.toggle_modal();
-                if let Some(action) = self.select_color_option(){
-                    self.color_list.select(Some(0));
+    //Left and right only do something on the rows that show a value
+    fn bindings(&self) -> Vec<Binding<SettingsView>>{
+        let mut bindings: Vec<Binding<SettingsView>> = vec![
+            Binding::action(KeyAction::Up, "Select", |view, _| {view.move_selection(false); ViewAction::Nothing}),
+            Binding::action(KeyAction::Down, "Select", |view, _| {view.move_selection(true); ViewAction::Nothing}),
+        ];
+        let selected = self.get_selected_menu_option();
+        if matches!(selected, Some(MenuOption::Personality | MenuOption::FirstMove | MenuOption::Variant | MenuOption::AiThinkTime | MenuOption::Language | MenuOption::Theme | MenuOption::ArtPack | MenuOption::Animations | MenuOption::TilePatterns | MenuOption::PlainText | MenuOption::KeyPreset)) {
+            bindings.push(Binding::action(KeyAction::Left, "Change value", |view, _| {view.change_selected_option(false); ViewAction::Nothing}));
+            bindings.push(Binding::action(KeyAction::Right, "Change value", |view, _| {view.change_selected_option(true); ViewAction::Nothing}));
+        }
+        bindings.push(Binding::action(KeyAction::Confirm, "Open or change", |view, _| view.select_menu_option().unwrap_or(ViewAction::Nothing)));
+        bindings.push(Binding::action(KeyAction::Back, "Back to menu", |_, _| ViewAction::Back));
+        bindings.push(Binding::help());
+        bindings
+    }
+
+    fn handle_mouse_menu(&mut self, event: MouseEvent) -> ViewAction{
+        let Some(index) = mouse::list_item_at(self.list_area, &self.main_list, self.rows.len(), event.column, event.row)
+            .filter(|index| self.option_at(*index).is_some()) else {
+            return ViewAction::Nothing
+        };
+        match event.kind {
+            MouseEventKind::Moved => self.main_list.select(Some(index)),
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.main_list.select(Some(index));
+                if let Some(action) = self.select_menu_option() {
                     return action;
                 }
             }
             _ => {}
         }
-
         ViewAction::Nothing
     }
+
 }
 impl ViewModel for SettingsView{
     fn render_widgets(&mut self, frame: &mut Frame){
+        let theme = self.settings.borrow().theme.clone();
 
-        let title = Art::settings();
+        let title = Art::title(Art::settings(), "Settings", self.settings.borrow().plain_text).patch_style(theme.banner_style());
 
         let main_layout = Layout::default()
             .direction(Direction::Horizontal)
@@ -228,47 +470,78 @@ impl ViewModel for SettingsView{
             .split(frame.area());
 
         let left_area = main_layout[0];
+        //The sections need more lines than the other menus, the title only takes what it needs
         let left_area_rects = Layout::default()
             .direction(Direction::Vertical)
+            .margin(1)
             .constraints([
-                Constraint::Percentage(50),
-                Constraint::Percentage(50),
+                Constraint::Length(title.height() as u16 + 1),
+                Constraint::Fill(1),
             ])
             .split(left_area);
 
         let left_area_top = left_area_rects[0]
-            .centered(Constraint::Length(title.width() as u16),Constraint::Percentage(50));
+            .centered_horizontally(Constraint::Length(title.width() as u16));
         let left_area_bottom = left_area_rects[1]
-            .centered(Constraint::Percentage(75),Constraint::Percentage(75));
+            .centered_horizontally(Constraint::Percentage(75));
 
         frame.render_widget(title, left_area_top);
 
-        let list_items = self.list_options.iter().map(|item|{item.as_str()}).collect::<Vec<&str>>();
-        let list = List::new(list_items)
-            .highlight_style(Style::new().reversed())
+        let list = List::new(self.list_items())
+            .highlight_style(theme.highlight_style())
             .highlight_symbol(">>")
             .repeat_highlight_symbol(true);
 
         frame.render_stateful_widget(list, left_area_bottom, &mut self.main_list);
+        self.list_area = left_area_bottom;
 
-        frame.render_widget(Block::new().borders(Borders::ALL).border_type(BorderType::Rounded), left_area);
-
-        //draw modal
-        self.draw_modal(frame)
+        frame.render_widget(Block::new().borders(Borders::ALL).border_type(BorderType::Rounded).border_style(theme.border_style()), left_area);
 
+        if let Some(input) = &self.engine_input {
+            let input_area = left_area_bottom.centered(Constraint::Percentage(100), Constraint::Length(3));
+            frame.render_widget(Clear, input_area);
+            input.render(frame, input_area, tr("Engine command, e.g. python3 engines/random_engine.py"));
+        }
     }
+
+    //Keys without a binding are typed into the engine command while it is edited
     fn handle_inputs(&mut self, key: KeyEvent) -> ViewAction {
-        if(self.show_modal){
-            return self.handle_input_modal(key)
-        }else{
-            return self.handle_input_menu(key);
+        if self.engine_input.is_some() {
+            let bindings = SettingsView::engine_input_bindings();
+            if let Some(view_action) = key_table::dispatch(self, &bindings, None, key) {
+                return view_action;
+            }
+            if let Some(input) = self.engine_input.as_mut() {
+                input.handle_key(key);
+            }
+            return ViewAction::Nothing;
         }
+        let action = self.settings.borrow().keymap.action_for(key);
+        let bindings = self.bindings();
+        key_table::dispatch(self, &bindings, action, key).unwrap_or(ViewAction::Nothing)
+    }
 
-        ViewAction::Nothing
+    fn help(&self) -> Vec<HelpEntry> {
+        let bindings = if self.engine_input.is_some() {SettingsView::engine_input_bindings()} else {self.bindings()};
+        key_table::help_entries(&bindings, &self.settings.borrow().keymap)
     }
 
-    fn additional_actions(&mut self) {
+    fn handle_mouse(&mut self, event: MouseEvent) -> ViewAction {
+        if self.engine_input.is_some() {
+            return ViewAction::Nothing;
+        }
+        self.handle_mouse_menu(event)
+    }
+
+    fn handle_result(&mut self, result: DialogResult) -> ViewAction {
+        if result == DialogResult::Confirmed(Confirmation::ResetSettings) {
+            self.reset_to_defaults();
+        }
+        ViewAction::Nothing
+    }
 
+    fn take_notifications(&mut self) -> Vec<Notification>{
+        std::mem::take(&mut self.notifications)
     }
 }
 
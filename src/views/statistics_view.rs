@@ -0,0 +1,160 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use ratatui::crossterm::event::KeyEvent;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Margin, Rect};
+use ratatui::style::Style;
+use ratatui::widgets::{Block, BorderType, Borders, Paragraph, Row, Sparkline, Table, TableState};
+use crate::enums::key_action::KeyAction;
+use crate::enums::view_action::ViewAction;
+use crate::helpers::key_table;
+use crate::helpers::key_table::{Binding, HelpEntry};
+use crate::services::locale::{tr, tr_args};
+use crate::services::profiles::ProfileStore;
+use crate::services::rating::INITIAL_RATING;
+use crate::services::records;
+use crate::services::records::GameRecord;
+use crate::services::settings::Settings;
+use crate::traits::view_model::ViewModel;
+use crate::services::game_art as Art;
+
+pub struct StatisticsView{
+    settings: Rc<RefCell<Settings>>,
+    profiles: Rc<RefCell<ProfileStore>>,
+    records: Vec<GameRecord>,
+    profiles_table: TableState,
+    error: Option<String>,
+}
+
+impl StatisticsView{
+    pub fn new(settings: Rc<RefCell<Settings>>, profiles: Rc<RefCell<ProfileStore>>) -> StatisticsView{
+        let mut table_state = TableState::default();
+        table_state.select(Some(0));
+
+        let (records, error) = match records::load_all(&settings.borrow().data_dir) {
+            Ok(records) => (records, None),
+            Err(error) => (Vec::new(), Some(tr_args("Could not read saved games: {}", &[&error]))),
+        };
+
+        StatisticsView{
+            settings,
+            profiles,
+            records,
+            profiles_table: table_state,
+            error,
+        }
+    }
+
+    /// Ratings of the profile after each rated game, starting from the initial rating.
+    fn rating_history(&self, name: &str) -> Vec<f64>{
+        let mut history = vec![INITIAL_RATING];
+        history.extend(self.records.iter().filter_map(|record| record.rating_of(name)));
+        history
+    }
+
+    fn render_history(&self, frame: &mut Frame, area: Rect){
+        let profiles = self.profiles.borrow();
+        let selected = self.profiles_table.selected().and_then(|index| profiles.profiles.get(index));
+        let block = Block::bordered().border_type(BorderType::Rounded);
+
+        let Some(profile) = selected else {
+            frame.render_widget(Paragraph::new(tr("Create a profile to track ratings")).centered().block(block.title(tr("Rating history"))), area);
+            return;
+        };
+
+        let history = self.rating_history(&profile.name);
+        let lowest = history.iter().cloned().fold(f64::MAX, f64::min);
+        let highest = history.iter().cloned().fold(f64::MIN, f64::max);
+        //Sparkline bars start at zero, shifting by the lowest rating keeps small changes visible
+        let data = history.iter().map(|rating| (rating - lowest) as u64 + 1).collect::<Vec<u64>>();
+
+        let title = tr_args("Rating history of {}: {} - {}, {} rated games", &[&profile.name, &format!("{:.0}", lowest), &format!("{:.0}", highest), &(history.len() - 1)]);
+        frame.render_widget(Sparkline::default().data(&data).block(block.title(title)), area);
+    }
+
+    fn bindings(&self) -> Vec<Binding<StatisticsView>>{
+        vec![
+            Binding::action(KeyAction::Up, "Select profile", |view, _| {view.profiles_table.select_previous(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Down, "Select profile", |view, _| {view.profiles_table.select_next(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Back, "Back to menu", |_, _| ViewAction::Back),
+            Binding::help(),
+        ]
+    }
+}
+
+impl ViewModel for StatisticsView{
+    fn render_widgets(&mut self, frame: &mut Frame){
+        let title = Art::title(Art::stats(), "Statistics", self.settings.borrow().plain_text);
+
+        let main_layout_rects = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(title.height() as u16 + 2),
+                Constraint::Fill(1),
+                Constraint::Length(7),
+                Constraint::Length(2),
+            ])
+            .split(frame.area());
+
+        let title_area = main_layout_rects[0]
+            .centered(Constraint::Length(title.width() as u16),Constraint::Length(title.height() as u16));
+        frame.render_widget(title, title_area);
+
+        let table_areas = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+            .margin(1)
+            .split(main_layout_rects[1]);
+
+        {
+            let profiles = self.profiles.borrow();
+            let header = Row::new(["Profile", "Rating", "Games", "Wins", "Losses", "Draws"].map(tr)).style(Style::new().bold());
+            let rows = profiles.profiles.iter().map(|profile| Row::new([
+                profile.name.clone(),
+                format!("{:.0}", profile.rating),
+                profile.games().to_string(),
+                profile.wins.to_string(),
+                profile.losses.to_string(),
+                profile.draws.to_string(),
+            ]));
+            let widths = [Constraint::Fill(1), Constraint::Length(6), Constraint::Length(6), Constraint::Length(6), Constraint::Length(6), Constraint::Length(6)];
+            let table = Table::new(rows, widths)
+                .header(header)
+                .row_highlight_style(Style::new().reversed())
+                .block(Block::bordered().border_type(BorderType::Rounded).title(tr("Profiles")));
+            frame.render_stateful_widget(table, table_areas[0], &mut self.profiles_table);
+
+            let header = Row::new(["AI level", "Rating", "Games"].map(tr)).style(Style::new().bold());
+            let rows = profiles.ai_ratings.iter().map(|ai| Row::new([
+                ai.level.label().to_string(),
+                format!("{:.0}", ai.rating),
+                ai.games.to_string(),
+            ]));
+            let widths = [Constraint::Fill(1), Constraint::Length(6), Constraint::Length(6)];
+            let table = Table::new(rows, widths)
+                .header(header)
+                .block(Block::bordered().border_type(BorderType::Rounded).title(tr("AI levels")));
+            frame.render_widget(table, table_areas[1]);
+        }
+
+        let history_area = main_layout_rects[2].inner(Margin::new(1,0));
+        self.render_history(frame, history_area);
+
+        let keymap = &self.settings.borrow().keymap;
+        let help_text = self.error.clone().unwrap_or(tr_args("{},{} - choose profile, {} - go back",
+            &[&keymap.label_for(KeyAction::Up), &keymap.label_for(KeyAction::Down), &keymap.label_for(KeyAction::Back)]));
+        frame.render_widget(Paragraph::new(help_text).centered(), main_layout_rects[3]);
+
+        frame.render_widget(Block::new().borders(Borders::ALL).border_type(BorderType::Rounded), frame.area());
+    }
+
+    fn handle_inputs(&mut self, key: KeyEvent) -> ViewAction {
+        let action = self.settings.borrow().keymap.action_for(key);
+        let bindings = self.bindings();
+        key_table::dispatch(self, &bindings, action, key).unwrap_or(ViewAction::Nothing)
+    }
+
+    fn help(&self) -> Vec<HelpEntry> {
+        key_table::help_entries(&self.bindings(), &self.settings.borrow().keymap)
+    }
+}
@@ -0,0 +1,124 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use ratatui::crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Constraint, Direction, Layout, Margin, Rect};
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Clear, List, ListState, Paragraph};
+use crate::enums::key_action::KeyAction;
+use crate::enums::theme_element::ThemeElement;
+use crate::enums::view_action::ViewAction;
+use crate::helpers::key_table;
+use crate::helpers::key_table::{Binding, HelpEntry};
+use crate::helpers::mouse;
+use crate::services::locale::tr_args;
+use crate::services::settings::Settings;
+use crate::traits::view_model::ViewModel;
+
+/// Overlay listing the theme elements with their colors, picking one opens the color picker.
+pub struct ThemeColorsView{
+    element_list: ListState,
+    element_list_area: Rect,
+    settings: Rc<RefCell<Settings>>,
+}
+
+impl ThemeColorsView{
+    pub fn new(settings: Rc<RefCell<Settings>>) -> ThemeColorsView{
+        let mut element_list = ListState::default();
+        element_list.select(Some(0));
+
+        ThemeColorsView{
+            element_list,
+            element_list_area: Rect::default(),
+            settings,
+        }
+    }
+
+    fn get_selected_element(&self) -> Option<ThemeElement>{
+        self.element_list.selected().and_then(|index| ThemeElement::get_all().get(index).copied())
+    }
+
+    fn open_selected_element(&self) -> ViewAction{
+        match self.get_selected_element() {
+            Some(element) => ViewAction::OpenColorPicker(element),
+            None => ViewAction::Nothing,
+        }
+    }
+
+    /// Element name with a swatch of its current color.
+    fn element_line(&self, element: ThemeElement) -> Line<'static>{
+        let color = self.settings.borrow().theme.color(element);
+        Line::from(vec![
+            Span::styled("   ", Style::new().bg(color)),
+            Span::raw(format!(" {}: {}", element.label(), color)),
+        ])
+    }
+
+    fn bindings(&self) -> Vec<Binding<ThemeColorsView>>{
+        vec![
+            Binding::action(KeyAction::Up, "Select", |view, _| {view.element_list.select_previous(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Down, "Select", |view, _| {view.element_list.select_next(); ViewAction::Nothing}),
+            Binding::action(KeyAction::Confirm, "Change color", |view, _| view.open_selected_element()),
+            Binding::action(KeyAction::Back, "Close", |_, _| ViewAction::Back),
+            Binding::help(),
+        ]
+    }
+}
+
+impl ViewModel for ThemeColorsView{
+    fn render_widgets(&mut self, frame: &mut Frame){
+        let theme = self.settings.borrow().theme.clone();
+        let popup_area = frame.area().centered(Constraint::Percentage(50),Constraint::Percentage(60));
+        let popup_area_rects = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Fill(1),Constraint::Length(2)])
+            .split(popup_area.inner(Margin::new(1,1)));
+
+        let block = Block::bordered().style(theme.base_style()).border_style(theme.border_style()).title(tr_args("Colors of {}", &[&theme.name])).title_alignment(Alignment::Center);
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(block, popup_area);
+
+        let list_items = ThemeElement::get_all().into_iter().map(|element| self.element_line(element)).collect::<Vec<Line>>();
+        let list = List::new(list_items)
+            .highlight_style(theme.highlight_style())
+            .highlight_symbol(">>")
+            .repeat_highlight_symbol(true);
+        frame.render_stateful_widget(list, popup_area_rects[0], &mut self.element_list);
+        self.element_list_area = popup_area_rects[0];
+
+        let keymap = &self.settings.borrow().keymap;
+        let help_text = tr_args("{} - change color, {} - close", &[&keymap.label_for(KeyAction::Confirm), &keymap.label_for(KeyAction::Back)]);
+        frame.render_widget(Paragraph::new(help_text).centered(), popup_area_rects[1]);
+    }
+
+    fn handle_inputs(&mut self, key: KeyEvent) -> ViewAction{
+        let action = self.settings.borrow().keymap.action_for(key);
+        let bindings = self.bindings();
+        key_table::dispatch(self, &bindings, action, key).unwrap_or(ViewAction::Nothing)
+    }
+
+    fn help(&self) -> Vec<HelpEntry>{
+        key_table::help_entries(&self.bindings(), &self.settings.borrow().keymap)
+    }
+
+    fn handle_mouse(&mut self, event: MouseEvent) -> ViewAction{
+        let item_count = ThemeElement::get_all().len();
+        let Some(index) = mouse::list_item_at(self.element_list_area, &self.element_list, item_count, event.column, event.row) else {
+            return ViewAction::Nothing
+        };
+        match event.kind {
+            MouseEventKind::Moved => self.element_list.select(Some(index)),
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.element_list.select(Some(index));
+                return self.open_selected_element();
+            }
+            _ => {}
+        }
+        ViewAction::Nothing
+    }
+
+    fn is_overlay(&self) -> bool{
+        true
+    }
+}